@@ -1,27 +1,160 @@
+use std::collections::{BTreeMap, HashSet};
 use std::env;
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-fn main() {
-    // only rebuild if the word list changes
-    println!("cargo:rerun-if-changed=word_list.txt");
+/// The languages which can be embedded besides English, as their ISO 639-1 code and name.
+/// Each is enabled by a `lang-CODE` feature and read from `lang/CODE.txt`, which is generated by
+/// `scripts/lang.py`.
+const LANGUAGES: [(&str, &str); 4] = [
+    ("de", "German"),
+    ("es", "Spanish"),
+    ("fr", "French"),
+    ("pt", "Portuguese"),
+];
 
-    // Build the word list
-    let word_list = std::fs::read_to_string("word_list.txt").unwrap();
+/// Whether words with `len` letters should be embedded, going by the enabled features.
+fn embedded(len: usize) -> bool {
+    env::var_os("CARGO_FEATURE_ALL_LENGTHS").is_some()
+        || env::var_os(format!("CARGO_FEATURE_LEN_{}", len)).is_some()
+}
+
+/// Writes the embedded words of one language as `_{PREFIX}_WORDS` and `_{PREFIX}_BY_LEN`.
+fn write_words<W: Write>(file: &mut W, prefix: &str, word_list: &str) {
     let mut set = phf_codegen::Set::new();
-    for line in word_list.lines() {
-        set.entry(line);
+    let mut by_len: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for line in word_list.lines().filter(|line| !line.starts_with('#')) {
+        let len = line.chars().count();
+        if embedded(len) {
+            set.entry(line);
+            by_len.entry(len).or_default().push(line);
+        }
     }
 
-    // Write the generated data structure
+    writeln!(
+        file,
+        "pub static _{}_WORDS: phf::Set<&'static str> = \n{};\n",
+        prefix,
+        set.build()
+    )
+    .unwrap();
+
+    for (len, words) in &mut by_len {
+        words.sort_unstable();
+        writeln!(
+            file,
+            "static _{}_LEN_{}: [&str; {}] = {:?};\n",
+            prefix,
+            len,
+            words.len(),
+            words
+        )
+        .unwrap();
+    }
+    writeln!(
+        file,
+        "pub static _{}_BY_LEN: &[(usize, &[&str])] = &[",
+        prefix
+    )
+    .unwrap();
+    for len in by_len.keys() {
+        writeln!(file, "    ({}, &_{}_LEN_{}),", len, prefix, len).unwrap();
+    }
+    writeln!(file, "];\n").unwrap();
+}
+
+fn main() {
+    // only rebuild if the word lists change
+    println!("cargo:rerun-if-changed=word_list.txt");
+    println!("cargo:rerun-if-changed=answers.txt");
+    println!("cargo:rerun-if-changed=frequency.txt");
+    println!("cargo:rerun-if-changed=lang");
+
     let path = Path::new(&env::var("OUT_DIR").unwrap()).join("codegen.rs");
     let mut file = BufWriter::new(File::create(&path).unwrap());
 
+    // Build the English word list, keeping only the lengths we need
+    let word_list = std::fs::read_to_string("word_list.txt").unwrap();
+    write_words(&mut file, "EN", &word_list);
+
+    // Build the guess list, which is every five letter word
+    let mut guesses = phf_codegen::Set::new();
+    if embedded(5) {
+        for line in word_list.lines().filter(|line| line.chars().count() == 5) {
+            guesses.entry(line);
+        }
+    }
+
+    // Build the answer list, which must be a subset of the guess list
+    let known: HashSet<_> = word_list.lines().collect();
+    let answer_list = std::fs::read_to_string("answers.txt").unwrap();
+    let mut answers = phf_codegen::Set::new();
+    for line in answer_list.lines() {
+        assert!(known.contains(line), "{} is not in word_list.txt", line);
+        assert_eq!(line.chars().count(), 5, "{} is not five letters long", line);
+        if embedded(5) {
+            answers.entry(line);
+        }
+    }
+
+    // Build the word counts, each of which is a word and a count separated by a space
+    let frequency_list = std::fs::read_to_string("frequency.txt").unwrap();
+    let mut frequencies = phf_codegen::Map::new();
+    for line in frequency_list.lines().filter(|line| !line.starts_with('#')) {
+        let (word, score) = line.split_once(' ').unwrap();
+        assert!(known.contains(word), "{} is not in word_list.txt", word);
+        score.parse::<u32>().unwrap();
+        if embedded(word.chars().count()) {
+            frequencies.entry(word, score);
+        }
+    }
+
     writeln!(
         &mut file,
-        "pub static _WORDS: phf::Set<&'static str> = \n{};\n",
-        set.build()
+        "pub static _GUESSES: phf::Set<&'static str> = \n{};\n",
+        guesses.build()
     )
     .unwrap();
+    writeln!(
+        &mut file,
+        "pub static _ANSWERS: phf::Set<&'static str> = \n{};\n",
+        answers.build()
+    )
+    .unwrap();
+    writeln!(
+        &mut file,
+        "pub static _FREQUENCIES: phf::Map<&'static str, u32> = \n{};\n",
+        frequencies.build()
+    )
+    .unwrap();
+
+    // Build the word lists for any other languages which are enabled
+    let mut dictionaries = vec![("en", "English")];
+    for (code, name) in LANGUAGES {
+        let feature = format!("CARGO_FEATURE_LANG_{}", code.to_uppercase());
+        if env::var_os(feature).is_none() {
+            continue;
+        }
+
+        let word_list = std::fs::read_to_string(format!("lang/{}.txt", code)).unwrap();
+        write_words(&mut file, &code.to_uppercase(), &word_list);
+        dictionaries.push((code, name));
+    }
+
+    writeln!(
+        &mut file,
+        "pub static _DICTIONARIES: &[crate::Dictionary] = &["
+    )
+    .unwrap();
+    for (code, name) in dictionaries {
+        let prefix = code.to_uppercase();
+        writeln!(
+            &mut file,
+            "    crate::Dictionary::embedded({:?}, {:?}, &_{}_WORDS, _{}_BY_LEN),",
+            code, name, prefix, prefix
+        )
+        .unwrap();
+    }
+    writeln!(&mut file, "];").unwrap();
 }
@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+"""Generates frequency.txt, a commonness score for words of word_list.txt.
+
+    python3 scripts/frequency.py path/to/r50k_base.tiktoken > frequency.txt
+
+The scores come from the vocabulary of GPT-2's byte pair encoding, `r50k_base`, which is
+published by OpenAI under the MIT licence at
+https://openaipublic.blob.core.windows.net/encodings/r50k_base.tiktoken and bundled in the
+`tiktoken` and `tiktoken-rs` packages.  The encoding was trained on WebText, about 8 million
+web pages of general English, and merges the most frequent pairs first, so a whole word gets a
+token of its own only if it's common, and the earlier its token the more common it is.
+
+A word's rank is the earliest token for it as a word, with a leading space and either lowercase
+or capitalised.  Its score is `2 + floor(10 * log10(50257 / rank))`, so a score ten higher means
+roughly ten times as common.  Every answer without a token of its own scores 1, below every
+word which has one, and other words without a token are left out.
+"""
+
+import base64
+import math
+import os
+import sys
+
+VOCAB_SIZE = 50257
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(__doc__.split('\n\n')[1])
+
+    ranks = {}
+    with open(sys.argv[1]) as vocabulary:
+        for line in vocabulary:
+            token, rank = line.split()
+            try:
+                token = base64.b64decode(token).decode('utf-8')
+            except UnicodeDecodeError:
+                continue
+            word = token[1:].lower()
+            if token.startswith(' ') and token[1:] in (word, word.capitalize()):
+                ranks[word] = min(int(rank), ranks.get(word, VOCAB_SIZE))
+
+    root = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..')
+    with open(os.path.join(root, 'word_list.txt')) as f:
+        words = f.read().split()
+    with open(os.path.join(root, 'answers.txt')) as f:
+        answers = set(f.read().split())
+
+    print('# Generated by scripts/frequency.py from the r50k_base (GPT-2) vocabulary, see there.')
+    print('# Each line is a word of word_list.txt and its score, ten higher being ten times as common.')
+    for word in words:
+        if word in ranks:
+            print(word, 2 + math.floor(10 * math.log10(VOCAB_SIZE / ranks[word])))
+        elif word in answers:
+            print(word, 1)
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,231 @@
+#!/usr/bin/env python3
+"""Generates the word lists in lang/ for the languages besides English.
+
+    python3 scripts/lang.py path/to/bip39 path/to/stopwords-iso.json
+
+Each list combines two curated sources with words gathered from the system's translations:
+
+* The BIP-39 mnemonic word lists, 2048 common words each for Spanish, French and Portuguese,
+  as bundled in `src/language/` of the `bip39` crate (CC0-1.0).
+* The stopwords-iso lists (MIT) of common function words, as bundled in
+  `src/iso/stopwords-iso.json` of the `stop-words` crate.
+* Words from the translated manual pages in /usr/share/man/CODE and the gettext catalogues in
+  /usr/share/locale/CODE/LC_MESSAGES, so the output depends on the packages installed.  The
+  lists in lang/ were built on Debian 12.  Only single words are taken from these.
+
+Translations of technical text are full of untranslated identifiers, so a translated word is
+only kept if:
+
+* it's three to ten letters long and written in the language's letters;
+* it isn't in the English text it was translated from, isn't the name of a command or manual
+  page, and isn't in bold in a manual page, where commands and options go;
+* it occurs at least three times in at least two files, and proportionally more often than in
+  the English originals;
+* it isn't an abbreviation, that is the start of a longer word which is usually followed by a
+  full stop in the middle of a sentence;
+* it's used in lowercase at least once, except in German, whose nouns are capitalised;
+* it isn't one of the few abbreviations in EXCLUDED which the rules above let through.
+"""
+
+import collections
+import gettext
+import glob
+import gzip
+import json
+import os
+import re
+import sys
+import unicodedata
+
+LANGUAGES = {
+    'de': {'dirs': ['de'], 'bip39': None, 'letters': 'äöüß', 'fold': False},
+    'es': {'dirs': ['es'], 'bip39': 'spanish', 'letters': 'ñ', 'fold': True},
+    'fr': {'dirs': ['fr'], 'bip39': 'french', 'letters': '', 'fold': True},
+    'pt': {'dirs': ['pt', 'pt_BR'], 'bip39': 'portuguese', 'letters': '', 'fold': True},
+}
+
+LATIN = set('abcdefghijklmnopqrstuvwxyz')
+ACCENTS = str.maketrans('àáâãäåçèéêëìíîïñòóôõöøùúûüýÿ', 'aaaaaaceeeeiiiinoooooouuuuyy')
+TOKEN = re.compile(r"(?<![\w'’.\ufffd-])([^\W\d_]+)(?![\w'’\ufffd-])")
+MIN_LEN, MAX_LEN = 3, 10
+
+# Sections of manual pages which hold names, paths and commands rather than prose
+SKIPPED_SECTIONS = re.compile(
+    r'TRADU|BERSETZ|AUTE?OR|AUTHOR|SIEHE|VOIR|VEJA|V.?ASE|SYNOP|SINOP|BERSICHT|DATEIEN|'
+    r'FICHIER|FICHEIRO|ARCHIVO|ARQUIVO|BEISPIEL|EXEMPL|EJEMPL'
+)
+# Abbreviations of month names and table columns, which are rarely followed by a full stop
+EXCLUDED = {
+    'de': {'entf'},
+    'es': {'aplicacio', 'desp', 'direcc'},
+    'fr': {'verif'},
+    'pt': {'abr', 'fev', 'mescl'},
+}
+BOLD = re.compile(r'\\fB.*?\\f[RP]')
+MARKS = {'`': '\u0300', "'": '\u0301', '^': '\u0302', '~': '\u0303', ':': '\u0308', ',': '\u0327'}
+
+
+def fold(word, language):
+    """Writes a lowercase word in the language's letters, or returns None if it can't be."""
+    if language['fold']:
+        word = ''.join(c if c in language['letters'] else c.translate(ACCENTS) for c in word)
+    return word if set(word) <= LATIN | set(language['letters']) else None
+
+
+def troff_to_text(text):
+    """Decodes the accents of a manual page and strips its other markup."""
+    text = re.sub(r"\\[(\[]([`'^~:,])([A-Za-z])\]?", lambda m: m.group(2) + MARKS[m.group(1)], text)
+    text = re.sub(r'\\\[u([0-9A-F]{4})\]', lambda m: chr(int(m.group(1), 16)), text)
+    text = text.replace('\\(ss', 'ß').replace('\\[ss]', 'ß')
+    text = re.sub(r'\\f[BIRP]|\\f\(..|\\[e&|^]|\\s[-+]?\d', ' ', text)
+    # Any other escape leaves a mark, so the words it was part of are dropped
+    text = re.sub(r'\\\(..|\\\[[^]]*\]|\\.', '\ufffd', text)
+    return unicodedata.normalize('NFC', text)
+
+
+def manual_page(path):
+    """The prose of a manual page, along with the text it has in bold."""
+    opener = gzip.open if path.endswith('.gz') else open
+    with opener(path, 'rb') as f:
+        lines = f.read().decode('utf-8', 'replace').splitlines()
+
+    keep, prose, bold = True, [], []
+    for line in lines:
+        if line.startswith('.SH'):
+            header = unicodedata.normalize('NFKD', troff_to_text(line[3:])).upper()
+            keep = not SKIPPED_SECTIONS.search(header)
+        elif line.startswith(('.', "'")):
+            bold.extend(BOLD.findall(line))
+        elif keep:
+            bold.extend(BOLD.findall(line))
+            prose.append(BOLD.sub(' ', line))
+    return troff_to_text('\n'.join(prose)), troff_to_text(' '.join(bold))
+
+
+def tokens(text):
+    """The words of `text` which are lowercase or capitalised, and whether each looks like an
+    abbreviation, with a full stop but no new sentence after it."""
+    for m in TOKEN.finditer(text):
+        word = m.group(1)
+        if word.islower() or (word[0].isupper() and word[1:].islower()):
+            rest = text[m.end():m.end() + 3]
+            following = rest[1:].lstrip()[:1]
+            yield word, rest[:1] == '.' and (following.islower() or following.isdigit())
+
+
+def words_of(text):
+    return {word.lower() for word, _ in tokens(text)}
+
+
+def sources(code, language):
+    """Each translated text, with the words of its English original which it should skip."""
+    for d in language['dirs']:
+        for path in sorted(glob.glob(f'/usr/share/man/{d}/man*/*')):
+            prose, bold = manual_page(path)
+            original = path.replace(f'/man/{d}/', '/man/')
+            skip = words_of(bold)
+            if os.path.exists(original):
+                skip |= words_of(manual_page(original)[0])
+            yield path, prose, skip
+
+        for path in sorted(glob.glob(f'/usr/share/locale/{d}/LC_MESSAGES/*.mo')):
+            # The catalogues of language, country and keyboard names are all proper nouns
+            if os.path.basename(path).startswith(('iso_', 'xkeyboard')):
+                continue
+            with open(path, 'rb') as f:
+                try:
+                    catalogue = gettext.GNUTranslations(f)._catalog
+                except (OSError, UnicodeDecodeError):
+                    continue
+            for original, translation in catalogue.items():
+                if isinstance(original, tuple):
+                    original = original[0]
+                # Column headings are mostly abbreviations
+                if original and translation != original and '   ' not in translation:
+                    yield path, translation, words_of(original)
+
+
+def english_counts():
+    """How often each word occurs in the English manual pages."""
+    counts = collections.Counter()
+    for path in glob.glob('/usr/share/man/man*/*'):
+        counts.update(word.lower() for word, _ in tokens(manual_page(path)[0]))
+    return counts
+
+
+def commands():
+    """The names of commands and manual pages, which are never translated."""
+    names = set()
+    for d in ['/usr/bin', '/usr/sbin', '/bin', '/sbin']:
+        names.update(n.lower() for n in os.listdir(d))
+    for path in glob.glob('/usr/share/man/**/*.gz', recursive=True):
+        names.add(os.path.basename(path).split('.')[0].lower())
+    return names
+
+
+def translated_words(code, language, english, english_total, skipped):
+    counts = collections.Counter()
+    stops = collections.Counter()
+    files = collections.defaultdict(set)
+    lowercase = set()
+    for path, text, skip in sources(code, language):
+        for token, stop in tokens(text):
+            word = fold(token.lower(), language)
+            if not word or token.lower() in skip:
+                continue
+            counts[word] += 1
+            stops[word] += stop
+            files[word].add(path)
+            if token.islower():
+                lowercase.add(word)
+
+    total = sum(counts.values())
+    words = set()
+    for word, count in counts.items():
+        if not MIN_LEN <= len(word) <= MAX_LEN or word in skipped:
+            continue
+        if count < 3 or len(files[word]) < 2:
+            continue
+        if count / total <= english[word] / english_total:
+            continue
+        if code != 'de' and word not in lowercase:
+            continue
+        words.add(word)
+
+    # Abbreviations cut a longer word short, and are usually followed by a full stop.
+    prefixes = {w[:n] for w in words for n in range(MIN_LEN, len(w))}
+    abbreviations = {w for w in prefixes & words if stops[w] * 2 > counts[w]}
+    return words - abbreviations - EXCLUDED[code]
+
+
+def curated_words(code, language, bip39, stopwords):
+    words = set(stopwords.get(code, []))
+    if language['bip39']:
+        with open(os.path.join(bip39, 'src', 'language', language['bip39'] + '.rs')) as f:
+            words.update(re.findall(r'"([^"]+)"', f.read()))
+    words = {fold(unicodedata.normalize('NFC', w.lower()), language) for w in words}
+    return {w for w in words if w and len(w) >= MIN_LEN}
+
+
+def main():
+    if len(sys.argv) != 3:
+        sys.exit(__doc__.split('\n\n')[1])
+
+    with open(sys.argv[2]) as f:
+        stopwords = json.load(f)
+    english = english_counts()
+    english_total = sum(english.values())
+    skipped = commands()
+
+    root = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..')
+    for code, language in LANGUAGES.items():
+        words = curated_words(code, language, sys.argv[1], stopwords)
+        words |= translated_words(code, language, english, english_total, skipped)
+        with open(os.path.join(root, 'lang', code + '.txt'), 'w') as f:
+            f.write('# Generated by scripts/lang.py, see there for the sources.\n')
+            f.writelines(w + '\n' for w in sorted(words))
+        print(code, len(words), file=sys.stderr)
+
+
+if __name__ == '__main__':
+    main()
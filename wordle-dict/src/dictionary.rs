@@ -0,0 +1,452 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+/// The words of each length, in alphabetical order.
+type ByLen = &'static [(usize, &'static [&'static str])];
+
+#[derive(Debug)]
+enum Words {
+    /// Words built in by `build.rs`.
+    Embedded {
+        set: &'static phf::Set<&'static str>,
+        by_len: ByLen,
+    },
+    /// Words loaded at runtime, keyed by their length.
+    Loaded(BTreeMap<usize, Vec<String>>),
+}
+
+/// A line which was dropped while loading a word list, see [`Dictionary::invalid_lines`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidLine {
+    /// The line number, counting from one.
+    pub number: usize,
+    /// The line as it appeared in the file.
+    pub text: String,
+}
+
+/// A word list for one language, either embedded or loaded at runtime.
+///
+/// Embedded dictionaries are looked up by their ISO 639-1 code with [`Dictionary::get`].
+/// English, whose words are [`WORDS`](crate::WORDS), is always available.  Other languages are
+/// each behind a feature: `lang-de` for German, `lang-es` for Spanish, `lang-fr` for French and
+/// `lang-pt` for Portuguese.  Their lists are generated by `scripts/lang.py`, which combines
+/// the BIP-39 word lists and stopwords-iso with words from the translated manual pages and
+/// messages of a Debian system, so they're a few thousand words each and lean towards
+/// technical vocabulary.  They're written in the letters of the matching
+/// `wordle_lib::alphabet`, so German keeps `ä`, `ö`, `ü` and `ß` and Spanish keeps `ñ`, while
+/// every other accent is dropped.  The `len-N` features apply to every language.
+///
+/// Other word lists can be loaded at runtime with [`Dictionary::load`].
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_dict::Dictionary;
+///
+/// let english = Dictionary::get("en").unwrap();
+/// assert_eq!(english.name(), "English");
+/// assert_eq!(english.len(), wordle_dict::WORDS.len());
+/// assert!(Dictionary::get("xx").is_none());
+///
+/// // Other languages are only available with their feature enabled.
+/// if let Some(spanish) = Dictionary::get("es") {
+///     assert_eq!(spanish.name(), "Spanish");
+///     assert!(spanish.iter().all(|w| !w.contains('á')));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Dictionary {
+    code: Cow<'static, str>,
+    name: Cow<'static, str>,
+    words: Words,
+    invalid: Vec<InvalidLine>,
+}
+
+impl Dictionary {
+    pub(crate) const fn embedded(
+        code: &'static str,
+        name: &'static str,
+        set: &'static phf::Set<&'static str>,
+        by_len: ByLen,
+    ) -> Self {
+        Self {
+            code: Cow::Borrowed(code),
+            name: Cow::Borrowed(name),
+            words: Words::Embedded { set, by_len },
+            invalid: Vec::new(),
+        }
+    }
+
+    /// The dictionary for the language with the given code, if it was embedded.
+    pub fn get(code: &str) -> Option<&'static Dictionary> {
+        Self::all().iter().find(|d| d.code == code)
+    }
+
+    /// Every embedded dictionary, starting with English.
+    pub fn all() -> &'static [Dictionary] {
+        crate::private::_DICTIONARIES
+    }
+
+    /// Loads a word list for the language with the given code from the file at `path`.
+    /// See [`Dictionary::from_reader`] for the format.
+    pub fn load<P: AsRef<Path>>(code: &str, path: P) -> io::Result<Self> {
+        Self::from_reader(code, File::open(path)?)
+    }
+
+    /// Reads a word list for the language with the given code, with one word per line.
+    ///
+    /// Words are lowercased, surrounding whitespace and blank lines are ignored, and duplicates
+    /// are removed.  Lines containing anything other than letters are dropped, and can be found
+    /// with [`Dictionary::invalid_lines`].  With the `gzip` feature, gzipped lists are
+    /// decompressed as they're read; otherwise they're an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wordle_dict::Dictionary;
+    ///
+    /// let list = "Crane\nslate\n\ncrane\nwon't\n";
+    /// let dictionary = Dictionary::from_reader("en", list.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(dictionary.iter().collect::<Vec<_>>(), ["crane", "slate"]);
+    /// assert_eq!(dictionary.invalid_lines()[0].number, 5);
+    /// ```
+    pub fn from_reader<R: Read>(code: &str, reader: R) -> io::Result<Self> {
+        let mut reader = BufReader::new(reader);
+        if reader.fill_buf()?.starts_with(&[0x1f, 0x8b]) {
+            return Self::from_lines(code, gunzip(reader)?);
+        }
+
+        Self::from_lines(code, reader)
+    }
+
+    fn from_lines<R: BufRead>(code: &str, reader: R) -> io::Result<Self> {
+        let mut words: BTreeMap<usize, BTreeSet<String>> = BTreeMap::new();
+        let mut invalid = Vec::new();
+
+        for (i, line) in reader.split(b'\n').enumerate() {
+            let line = line?;
+            let word = match std::str::from_utf8(&line) {
+                Ok(line) => line.trim().to_lowercase(),
+                Err(_) => String::new(),
+            };
+
+            if word.is_empty() && line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            if word.is_empty() || !word.chars().all(char::is_alphabetic) {
+                invalid.push(InvalidLine {
+                    number: i + 1,
+                    text: String::from_utf8_lossy(&line).trim_end().to_owned(),
+                });
+                continue;
+            }
+
+            words.entry(word.chars().count()).or_default().insert(word);
+        }
+
+        // Loaded lists take the name of the embedded language with the same code, if any.
+        let name = match Self::get(code) {
+            Some(embedded) => embedded.name.clone(),
+            None => Cow::Owned(code.to_owned()),
+        };
+
+        Ok(Self {
+            code: Cow::Owned(code.to_owned()),
+            name,
+            words: Words::Loaded(
+                words
+                    .into_iter()
+                    .map(|(len, words)| (len, words.into_iter().collect()))
+                    .collect(),
+            ),
+            invalid,
+        })
+    }
+
+    /// The language's ISO 639-1 code, such as `"en"`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// The language's name in English, or its code if the language isn't known.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The number of words.
+    pub fn len(&self) -> usize {
+        match &self.words {
+            Words::Embedded { set, .. } => set.len(),
+            Words::Loaded(by_len) => by_len.values().map(Vec::len).sum(),
+        }
+    }
+
+    /// Returns `true` if there are no words.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if `word` is in the dictionary.
+    pub fn contains(&self, word: &str) -> bool {
+        match &self.words {
+            Words::Embedded { set, .. } => set.contains(word),
+            Words::Loaded(by_len) => by_len
+                .get(&word.chars().count())
+                .is_some_and(|words| words.binary_search_by(|w| w.as_str().cmp(word)).is_ok()),
+        }
+    }
+
+    /// Every word, shortest first and then in alphabetical order.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.lengths().flat_map(move |len| self.words_of_len(len)))
+    }
+
+    /// The words with `len` letters, in alphabetical order.
+    pub fn words_of_len(&self, len: usize) -> Box<dyn Iterator<Item = &str> + '_> {
+        match &self.words {
+            Words::Embedded { by_len, .. } => {
+                Box::new(crate::find_len(by_len, len).iter().copied())
+            }
+            Words::Loaded(by_len) => Box::new(
+                by_len
+                    .get(&len)
+                    .into_iter()
+                    .flat_map(|words| words.iter().map(String::as_str)),
+            ),
+        }
+    }
+
+    /// The lengths of the words, in increasing order.
+    pub fn lengths(&self) -> Box<dyn Iterator<Item = usize> + '_> {
+        match &self.words {
+            Words::Embedded { by_len, .. } => Box::new(by_len.iter().map(|&(len, _)| len)),
+            Words::Loaded(by_len) => Box::new(by_len.keys().copied()),
+        }
+    }
+
+    /// The lines which were dropped while loading the dictionary, which is empty for embedded
+    /// dictionaries.
+    pub fn invalid_lines(&self) -> &[InvalidLine] {
+        &self.invalid
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gunzip<R: BufRead>(reader: R) -> io::Result<impl BufRead> {
+    Ok(BufReader::new(flate2::bufread::MultiGzDecoder::new(reader)))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gunzip<R: BufRead>(_reader: R) -> io::Result<R> {
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "reading gzipped word lists requires the `gzip` feature",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks an embedded dictionary's words are sorted, lowercase letters and as long as the
+    /// length they're listed under.
+    fn check_embedded(dictionary: &Dictionary) {
+        assert!(dictionary.invalid_lines().is_empty());
+        assert_eq!(dictionary.iter().count(), dictionary.len());
+        for len in dictionary.lengths() {
+            let words: Vec<_> = dictionary.words_of_len(len).collect();
+            assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+            for word in words {
+                assert_eq!(word.chars().count(), len);
+                assert!(word.chars().all(|c| c.is_alphabetic() && c.is_lowercase()));
+                assert!(dictionary.contains(word));
+            }
+        }
+    }
+
+    #[test]
+    fn loaded() {
+        let list = "slate\nCrane\n  apple \n\nbee\ncrane\nsea-side\n";
+        let dictionary = Dictionary::from_reader("en", list.as_bytes()).unwrap();
+        assert_eq!(dictionary.code(), "en");
+        assert_eq!(dictionary.name(), "English");
+        assert_eq!(dictionary.len(), 4);
+        assert!(!dictionary.is_empty());
+        assert!(dictionary.contains("crane") && dictionary.contains("bee"));
+        assert!(!dictionary.contains("Crane") && !dictionary.contains("sea-side"));
+        assert_eq!(dictionary.lengths().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(
+            dictionary.words_of_len(5).collect::<Vec<_>>(),
+            ["apple", "crane", "slate"]
+        );
+        assert_eq!(dictionary.words_of_len(4).count(), 0);
+        assert_eq!(
+            dictionary.invalid_lines(),
+            [InvalidLine {
+                number: 7,
+                text: "sea-side".to_owned(),
+            }]
+        );
+
+        let unknown = Dictionary::from_reader("xx", "".as_bytes()).unwrap();
+        assert_eq!(unknown.name(), "xx");
+        assert!(unknown.is_empty());
+        assert_eq!(unknown.lengths().count(), 0);
+    }
+
+    #[test]
+    fn loaded_non_ascii() {
+        let list = "Señor\nárbol\ngrößE\n";
+        let dictionary = Dictionary::from_reader("es", list.as_bytes()).unwrap();
+        assert_eq!(
+            dictionary.words_of_len(5).collect::<Vec<_>>(),
+            ["größe", "señor", "árbol"]
+        );
+        assert!(dictionary.contains("señor"));
+        assert!(dictionary.invalid_lines().is_empty());
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let list = "crane\r\nslate\r\n\r\nwon't\r\n";
+        let dictionary = Dictionary::from_reader("en", list.as_bytes()).unwrap();
+        assert_eq!(dictionary.iter().collect::<Vec<_>>(), ["crane", "slate"]);
+        assert!(dictionary.contains("slate"));
+        assert_eq!(
+            dictionary.invalid_lines(),
+            [InvalidLine {
+                number: 4,
+                text: "won't".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let list = b"crane\n\xff\xfeoops\nslate\nbad\xc3\n";
+        let dictionary = Dictionary::from_reader("en", &list[..]).unwrap();
+        assert_eq!(dictionary.iter().collect::<Vec<_>>(), ["crane", "slate"]);
+        let numbers: Vec<_> = dictionary
+            .invalid_lines()
+            .iter()
+            .map(|l| l.number)
+            .collect();
+        assert_eq!(numbers, [2, 4]);
+        assert_eq!(dictionary.invalid_lines()[0].text, "\u{fffd}\u{fffd}oops");
+        assert_eq!(dictionary.invalid_lines()[1].text, "bad\u{fffd}");
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let err = Dictionary::load("en", "does/not/exist.txt").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_round_trip() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let list = "slate\r\ncrane\nbee\nwon't\n";
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(list.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let plain = Dictionary::from_reader("en", list.as_bytes()).unwrap();
+        let dictionary = Dictionary::from_reader("en", &gzipped[..]).unwrap();
+        assert_eq!(
+            dictionary.iter().collect::<Vec<_>>(),
+            plain.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(dictionary.invalid_lines(), plain.invalid_lines());
+        assert!(dictionary.contains("crane"));
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn gzip_needs_feature() {
+        let gzipped = [0x1f, 0x8b, 0x08, 0x00];
+        let err = Dictionary::from_reader("en", &gzipped[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("`gzip` feature"));
+    }
+
+    #[test]
+    fn english() {
+        let english = Dictionary::get("en").unwrap();
+        assert_eq!(english.code(), "en");
+        assert_eq!(english.len(), crate::WORDS.len());
+        assert_eq!(Dictionary::all()[0].code(), "en");
+        check_embedded(english);
+    }
+
+    #[cfg(feature = "lang-de")]
+    #[test]
+    fn german() {
+        let german = Dictionary::get("de").unwrap();
+        assert_eq!(german.name(), "German");
+        check_embedded(german);
+        if german.lengths().any(|len| len == 5) {
+            assert!(german.words_of_len(5).count() > 300);
+            assert!(german.contains("größe") && german.contains("zeile"));
+            assert!(german.contains("übrig") && !german.contains("ubrig"));
+        }
+        assert!(german.iter().all(|w| !w.contains(['é', 'ñ'])));
+        assert!(!german.contains("archivstatusdatei") && !german.contains("entf"));
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn spanish() {
+        let spanish = Dictionary::get("es").unwrap();
+        assert_eq!(spanish.name(), "Spanish");
+        check_embedded(spanish);
+        if spanish.lengths().any(|len| len == 5) {
+            assert!(spanish.words_of_len(5).count() > 500);
+            assert!(spanish.contains("señor") && spanish.contains("arbol"));
+            assert!(!spanish.contains("árbol"));
+        }
+        assert!(!spanish.contains("abiflags") && !spanish.contains("direcc"));
+        assert!(spanish
+            .iter()
+            .all(|w| !w.contains(['á', 'é', 'í', 'ó', 'ú'])));
+    }
+
+    #[cfg(feature = "lang-fr")]
+    #[test]
+    fn french() {
+        let french = Dictionary::get("fr").unwrap();
+        assert_eq!(french.name(), "French");
+        check_embedded(french);
+        if french.lengths().any(|len| len == 5) {
+            assert!(french.words_of_len(5).count() > 300);
+            assert!(french.contains("ecran") && french.contains("texte"));
+        }
+        assert!(!french.contains("abicalls") && !french.contains("abiflags"));
+        assert!(french
+            .iter()
+            .all(|w| w.chars().all(|c| c.is_ascii_lowercase())));
+    }
+
+    #[cfg(feature = "lang-pt")]
+    #[test]
+    fn portuguese() {
+        let portuguese = Dictionary::get("pt").unwrap();
+        assert_eq!(portuguese.name(), "Portuguese");
+        check_embedded(portuguese);
+        if portuguese.lengths().any(|len| len == 5) {
+            assert!(portuguese.words_of_len(5).count() > 500);
+            assert!(portuguese.contains("opcao") && portuguese.contains("linha"));
+        }
+        assert!(!portuguese.contains("alinh") && !portuguese.contains("fev"));
+        assert!(portuguese
+            .iter()
+            .all(|w| w.chars().all(|c| c.is_ascii_lowercase())));
+    }
+}
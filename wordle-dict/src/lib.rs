@@ -1,3 +1,7 @@
+mod dictionary;
+
+pub use dictionary::{Dictionary, InvalidLine};
+
 mod private {
     include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
 }
@@ -5,13 +9,192 @@ mod private {
 // CLion can't see through the include! above so re-export here.
 /// This is a set of lowercase English words sourced from <https://github.com/dwyl/english-words>.
 ///
+/// By default words of every length are included.  To keep binaries small, turn off the default
+/// `all-lengths` feature and enable `len-N` for each length needed instead; only words of those
+/// lengths are then embedded, here and in every other list.
+///
+/// It's broad enough to accept almost anything a player might guess, but most of its words are
+/// too obscure to ever be an answer; see [`ANSWERS`] for those.
+///
 /// # Example
 ///
 /// ```rust
 /// use wordle_dict::WORDS;
 ///
+/// #[cfg(feature = "all-lengths")]
 /// assert_eq!(WORDS.len(), 370_102);
-/// assert_eq!(WORDS.contains("apple"), true);
-/// assert_eq!(WORDS.contains("notarealword"), false);
+/// if wordle_dict::lengths().any(|len| len == 5) {
+///     assert!(WORDS.contains("apple"));
+/// }
+/// assert!(!WORDS.contains("notarealword"));
+/// ```
+pub static WORDS: &phf::Set<&'static str> = &private::_EN_WORDS;
+
+/// The allowed guesses in a game of five letter words, which are all the five letter words in
+/// [`WORDS`].  Like [`ANSWERS`], this is left out unless five letter words are embedded.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_dict::GUESSES;
+///
+/// assert_eq!(GUESSES.len(), wordle_dict::words_of_len(5).len());
+/// assert!(GUESSES.iter().all(|w| w.len() == 5));
+/// assert!(!GUESSES.contains("apples"));
+/// ```
+pub static GUESSES: &phf::Set<&'static str> = &private::_GUESSES;
+
+/// A hand-picked set of common five letter words which make sensible answers.
+///
+/// Every answer is also in [`GUESSES`], so can be guessed, and so is left out unless five
+/// letter words are embedded.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_dict::{ANSWERS, GUESSES};
+///
+/// if !ANSWERS.is_empty() {
+///     assert!(ANSWERS.contains("crane"));
+///     assert!(!ANSWERS.contains("aahed"));
+///     assert!(GUESSES.contains("aahed"));
+/// }
+/// assert!(ANSWERS.iter().all(|w| w.len() == 5 && GUESSES.contains(w)));
 /// ```
-pub static WORDS: &phf::Set<&'static str> = &private::_WORDS;
+pub static ANSWERS: &phf::Set<&'static str> = &private::_ANSWERS;
+
+/// How common each of the [`WORDS`] is in general English, on a logarithmic scale where a score
+/// ten higher means roughly ten times as common.  Every one of the [`ANSWERS`] is listed, with
+/// `1` for the rarest; other words are listed only if they're common enough to have a score.
+///
+/// The scores are derived from word ranks in the vocabulary of GPT-2's byte pair encoding, which
+/// was trained on web pages, by `scripts/frequency.py`; see there for the details.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_dict::FREQUENCIES;
+///
+/// if let (Some(house), Some(crane)) = (FREQUENCIES.get("house"), FREQUENCIES.get("crane")) {
+///     assert!(house > crane);
+/// }
+/// assert!(wordle_dict::ANSWERS.iter().all(|w| FREQUENCIES.contains_key(w)));
+/// assert_eq!(FREQUENCIES.get("xylyl"), None);
+/// ```
+pub static FREQUENCIES: &phf::Map<&'static str, u32> = &private::_FREQUENCIES;
+
+/// The embedded English words with `len` letters, in alphabetical order.  This is empty if
+/// words of that length weren't embedded, see [`WORDS`].
+///
+/// # Example
+///
+/// ```rust
+/// let words = wordle_dict::words_of_len(5);
+///
+/// if !words.is_empty() {
+///     assert_eq!(words.len(), 15_917);
+///     assert!(words.binary_search(&"crane").is_ok());
+/// }
+/// assert!(wordle_dict::words_of_len(99).is_empty());
+/// ```
+pub fn words_of_len(len: usize) -> &'static [&'static str] {
+    find_len(private::_EN_BY_LEN, len)
+}
+
+/// The lengths of the embedded English words, in increasing order.
+pub fn lengths() -> impl Iterator<Item = usize> {
+    private::_EN_BY_LEN.iter().map(|&(len, _)| len)
+}
+
+fn find_len(
+    by_len: &'static [(usize, &'static [&'static str])],
+    len: usize,
+) -> &'static [&'static str] {
+    by_len
+        .iter()
+        .find(|&&(l, _)| l == len)
+        .map_or(&[], |&(_, words)| words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether each `len-N` feature is enabled, starting from `len-1`.
+    const LEN_FEATURES: [bool; 15] = [
+        cfg!(feature = "len-1"),
+        cfg!(feature = "len-2"),
+        cfg!(feature = "len-3"),
+        cfg!(feature = "len-4"),
+        cfg!(feature = "len-5"),
+        cfg!(feature = "len-6"),
+        cfg!(feature = "len-7"),
+        cfg!(feature = "len-8"),
+        cfg!(feature = "len-9"),
+        cfg!(feature = "len-10"),
+        cfg!(feature = "len-11"),
+        cfg!(feature = "len-12"),
+        cfg!(feature = "len-13"),
+        cfg!(feature = "len-14"),
+        cfg!(feature = "len-15"),
+    ];
+
+    /// Whether words with `len` letters should have been embedded, going by the features.
+    fn embedded(len: usize) -> bool {
+        cfg!(feature = "all-lengths") || LEN_FEATURES.get(len.wrapping_sub(1)) == Some(&true)
+    }
+
+    #[test]
+    fn embedded_lengths() {
+        for len in 1..=15 {
+            assert_eq!(
+                !words_of_len(len).is_empty(),
+                embedded(len),
+                "length {}",
+                len
+            );
+        }
+        assert!(lengths().all(embedded));
+        assert_eq!(ANSWERS.is_empty(), !embedded(5));
+        assert_eq!(GUESSES.len(), words_of_len(5).len());
+        assert!(words_of_len(5).iter().all(|w| GUESSES.contains(w)));
+
+        for len in lengths() {
+            let words = words_of_len(len);
+            assert!(words
+                .iter()
+                .all(|w| w.chars().count() == len && WORDS.contains(w)));
+            assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+        let total: usize = lengths().map(|len| words_of_len(len).len()).sum();
+        assert_eq!(WORDS.len(), total);
+    }
+
+    #[test]
+    fn frequencies() {
+        assert!(ANSWERS
+            .iter()
+            .all(|w| FREQUENCIES.get(w).is_some_and(|&s| s >= 1)));
+        assert!(FREQUENCIES.keys().all(|w| WORDS.contains(w)));
+        if embedded(5) {
+            assert!(FREQUENCIES["about"] > FREQUENCIES["house"]);
+            assert!(FREQUENCIES["house"] > FREQUENCIES["crane"]);
+            assert!(FREQUENCIES["crane"] > FREQUENCIES["flute"]);
+            assert_eq!(FREQUENCIES["flute"], 1);
+        }
+    }
+
+    #[cfg(not(feature = "all-lengths"))]
+    #[test]
+    fn reduced_lengths() {
+        // Without `all-lengths` nothing longer than the `len-N` features cover is embedded.
+        assert!(lengths().all(|len| len <= 15));
+        if embedded(5) {
+            assert_eq!(words_of_len(5).len(), 15_917);
+            assert!(WORDS.contains("crane"));
+        }
+        if !embedded(6) {
+            assert!(!WORDS.contains("chains"));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::alphabet::{Alphabet, English};
+use crate::feedback::pattern;
+use crate::{DynEngine, Feedback, Inference, Session};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// A host which never commits to an answer, as in Absurdle.
+///
+/// After each guess the adversary splits the words which could still be the answer by the
+/// feedback the guess would receive, and gives whichever feedback keeps the most of them alive.
+/// Ties go to the pattern with the lowest [`Feedback::code`], which reveals the least.  The game
+/// only ends once a single word is left and it is guessed.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::adversary::Adversary;
+/// use wordle_lib::DynEngine;
+///
+/// let engine = DynEngine::new(3, ["bat", "cat", "hat", "dot", "dog"]);
+/// let mut adversary = Adversary::new(engine);
+///
+/// // `dog` would be the only answer if the `d` were right, so the adversary says it isn't.
+/// assert_eq!(adversary.guess("dog").to_string(), "BBB");
+/// assert_eq!(adversary.candidates(), ["bat", "cat", "hat"]);
+/// ```
+pub struct Adversary<'w, A: Alphabet = English> {
+    session: Session<'w, A>,
+}
+
+impl<'w, A: Alphabet> Adversary<'w, A> {
+    /// Starts a game in which every word known to `engine` could be the answer.
+    pub fn new<E: Into<Arc<DynEngine<'w, A>>>>(engine: E) -> Self {
+        Self {
+            session: Session::new(engine),
+        }
+    }
+
+    /// The game so far, as seen by the player.
+    pub fn session(&self) -> &Session<'w, A> {
+        &self.session
+    }
+
+    /// The words which could still be the answer.
+    pub fn candidates(&self) -> &[&'w str] {
+        self.session.candidates()
+    }
+
+    /// Scores `guess`, choosing the feedback which leaves the most candidates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no word could be the answer, or if `guess` has the wrong length.
+    pub fn guess(&mut self, guess: &str) -> Feedback {
+        // The size of each bucket, and one of the words in it.
+        let mut buckets: BTreeMap<usize, (usize, &str)> = BTreeMap::new();
+        for &candidate in self.candidates() {
+            buckets
+                .entry(pattern::<A>(guess, candidate))
+                .or_insert((0, candidate))
+                .0 += 1;
+        }
+
+        let (_, &(_, answer)) = buckets
+            .iter()
+            .max_by(|(lc, (l, _)), (rc, (r, _))| l.cmp(r).then(rc.cmp(lc)))
+            .expect("no word could be the answer");
+
+        let feedback = Feedback::score_in::<A, _, _>(guess, answer);
+        self.session
+            .apply(guess, &feedback)
+            .expect("feedback from a candidate can't contradict earlier turns");
+        feedback
+    }
+
+    /// Plays `strategy` against the adversary, allowing at most `max_guesses` guesses, and
+    /// returns the number of guesses taken to pin it down.
+    ///
+    /// The strategy is called like the ones given to [`simulate`](crate::simulate::simulate).
+    pub fn play<F>(&mut self, max_guesses: usize, mut strategy: F) -> Option<usize>
+    where
+        F: FnMut(&DynEngine<'w, A>, &[Inference<A>]) -> Option<&'w str>,
+    {
+        for turn in 1..=max_guesses {
+            let inferences = self.session.knowledge().inferences();
+            let guess = strategy(self.session.engine(), &inferences)?;
+            if self.guess(guess).is_solved() {
+                return Some(turn);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Adversary;
+    use crate::scorer::MinimaxScorer;
+    use crate::{simulate, DynEngine};
+
+    const WORDS: [&str; 6] = ["bat", "cat", "hat", "mat", "dot", "dog"];
+
+    #[test]
+    fn adversary_keeps_largest_bucket() {
+        let mut adversary = Adversary::new(DynEngine::new(3, WORDS));
+
+        // `bat`, `cat`, `hat` and `mat` all give `BGG`.
+        assert_eq!(adversary.guess("oat").to_string(), "BGG");
+        assert_eq!(adversary.candidates(), ["bat", "cat", "hat", "mat"]);
+
+        assert_eq!(adversary.guess("bat").to_string(), "BGG");
+        assert_eq!(adversary.candidates(), ["cat", "hat", "mat"]);
+
+        assert!(!adversary.guess("cat").is_solved());
+        assert!(!adversary.guess("hat").is_solved());
+        assert_eq!(adversary.candidates(), ["mat"]);
+        assert!(adversary.guess("mat").is_solved());
+        assert_eq!(adversary.session().len(), 5);
+    }
+
+    #[test]
+    fn adversary_play() {
+        let engine = DynEngine::new(3, WORDS).scorer(MinimaxScorer);
+        let worst = simulate::simulate(&engine, &WORDS, 6, simulate::best)
+            .slowest(1)
+            .first()
+            .map(|&(_, guesses)| guesses);
+
+        // The adversary is at least as hard to pin down as any fixed answer.
+        let mut adversary = Adversary::new(engine);
+        let guesses = adversary.play(6, simulate::best);
+        assert!(guesses.is_some());
+        assert!(guesses >= worst);
+
+        let mut adversary = Adversary::new(DynEngine::new(3, WORDS));
+        assert_eq!(adversary.play(2, |_, _| Some("dog")), None);
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// The letters a Wordle variant is played with.
+///
+/// An alphabet decides which characters count as letters, and how characters typed by a player
+/// are folded into those letters: case is always ignored, and alphabets with
+/// [`FOLD_ACCENTS`](Alphabet::FOLD_ACCENTS) set also ignore any accent that isn't part of a
+/// letter of its own.  Word lists are expected to be written in the alphabet's letters already.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::alphabet::{Alphabet, English, Spanish};
+///
+/// assert_eq!(English::fold('Q'), Some('q'));
+/// assert_eq!(English::fold('ñ'), None);
+///
+/// // `ñ` is a letter in Spanish, but accents on vowels are ignored.
+/// assert_eq!(Spanish::fold('Ñ'), Some('ñ'));
+/// assert_eq!(Spanish::fold('á'), Some('a'));
+/// ```
+pub trait Alphabet: Copy + Default + Debug + Hash + Eq + Send + Sync + 'static {
+    /// Every letter of the alphabet, in lowercase.
+    const LETTERS: &'static [char];
+
+    /// Whether accented characters which aren't letters of the alphabet are folded into their
+    /// unaccented letter.
+    const FOLD_ACCENTS: bool = false;
+
+    /// The letter `c` stands for, or `None` if it doesn't stand for any.
+    fn fold(c: char) -> Option<char> {
+        let c = lowercase(c);
+        if Self::LETTERS.contains(&c) {
+            return Some(c);
+        }
+
+        let base = strip_accent(c);
+        if Self::FOLD_ACCENTS && Self::LETTERS.contains(&base) {
+            Some(base)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Alphabet::fold`], but characters which aren't letters are only lowercased.
+    fn normalize(c: char) -> char {
+        Self::fold(c).unwrap_or_else(|| lowercase(c))
+    }
+
+    /// The position of the letter `c` stands for within [`LETTERS`](Alphabet::LETTERS).
+    fn index(c: char) -> Option<usize> {
+        let c = Self::fold(c)?;
+        Self::LETTERS.iter().position(|&l| l == c)
+    }
+}
+
+const LATIN: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+/// The 26 letters of the English alphabet.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct English;
+
+impl Alphabet for English {
+    const LETTERS: &'static [char] = &LATIN;
+}
+
+/// The Spanish alphabet, which adds `ñ` and ignores accents on vowels.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Spanish;
+
+impl Alphabet for Spanish {
+    const LETTERS: &'static [char] = &[
+        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'ñ', 'o', 'p', 'q',
+        'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    ];
+    const FOLD_ACCENTS: bool = true;
+}
+
+/// The German alphabet, which adds `ä`, `ö`, `ü` and `ß`.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct German;
+
+impl Alphabet for German {
+    const LETTERS: &'static [char] = &[
+        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r',
+        's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ü', 'ß',
+    ];
+}
+
+/// The 26 letters used by French Wordles, which ignore accents and cedillas.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct French;
+
+impl Alphabet for French {
+    const LETTERS: &'static [char] = &LATIN;
+    const FOLD_ACCENTS: bool = true;
+}
+
+/// The 26 letters used by Portuguese Wordles, which ignore accents and cedillas.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Portuguese;
+
+impl Alphabet for Portuguese {
+    const LETTERS: &'static [char] = &LATIN;
+    const FOLD_ACCENTS: bool = true;
+}
+
+/// The Danish, Norwegian and Swedish letters, which add `å`, `ä`, `æ`, `ö` and `ø`.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Nordic;
+
+impl Alphabet for Nordic {
+    const LETTERS: &'static [char] = &[
+        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r',
+        's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'å', 'ä', 'æ', 'ö', 'ø',
+    ];
+}
+
+/// Lowercases `c`, leaving it alone if its lowercase form is more than one character.
+pub(crate) fn lowercase(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+/// Removes any accent from a lowercase Latin letter.
+fn strip_accent(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alphabet, English, French, German, Nordic, Portuguese, Spanish};
+
+    #[test]
+    fn alphabet_fold() {
+        assert_eq!(English::fold('A'), Some('a'));
+        assert_eq!(English::fold('é'), None);
+        assert_eq!(English::fold('-'), None);
+        assert_eq!(English::normalize('É'), 'é');
+
+        assert_eq!(Spanish::fold('Ü'), Some('u'));
+        assert_eq!(Spanish::fold('ñ'), Some('ñ'));
+
+        assert_eq!(German::fold('Ü'), Some('ü'));
+        assert_eq!(German::fold('ß'), Some('ß'));
+        assert_eq!(German::fold('é'), None);
+
+        assert_eq!(French::fold('Ê'), Some('e'));
+        assert_eq!(French::fold('ï'), Some('i'));
+
+        assert_eq!(Portuguese::fold('Ç'), Some('c'));
+        assert_eq!(Portuguese::fold('ã'), Some('a'));
+
+        assert_eq!(Nordic::fold('Ø'), Some('ø'));
+    }
+
+    #[test]
+    fn alphabet_index() {
+        assert_eq!(English::index('a'), Some(0));
+        assert_eq!(English::index('Z'), Some(25));
+        assert_eq!(Spanish::index('ñ'), Some(14));
+        assert_eq!(Spanish::index('o'), Some(15));
+        assert_eq!(German::index('ß'), Some(29));
+        assert_eq!(English::index('ß'), None);
+    }
+}
@@ -0,0 +1,187 @@
+//! Plays every answer in the dictionary with a given strategy and reports how well it did.
+//! Each turn guesses the word the strategy ranks highest among those which could still be the
+//! answer.  Only the opening guess given with `--start` may be any five letter word in the
+//! dictionary.
+//!
+//! ```text
+//! cargo run --release --bin simulate -- [--strategy overlap|entropy|minimax|frequency]
+//!     [--start WORD] [--max-guesses N] [--limit N] [--patterns FILE] [--words FILE]
+//! ```
+//!
+//! With `--patterns`, feedback is looked up in a precomputed table stored in `FILE`, which is
+//! built and saved on the first run.
+//!
+//! With `--words`, the dictionary is read from `FILE` instead, with one word per line, and every
+//! five letter word in it may be the answer.  Gzipped files can be read by also passing
+//! `--features wordle-dict/gzip`.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::process::exit;
+use wordle_dict::Dictionary;
+use wordle_lib::scorer::{EntropyScorer, FrequencyScorer, MinimaxScorer, OverlapScorer};
+use wordle_lib::{simulate, Engine, PatternTable, Scorer};
+
+struct Args {
+    scorer: Box<dyn Scorer>,
+    start: Option<String>,
+    max_guesses: usize,
+    limit: Option<usize>,
+    patterns: Option<PathBuf>,
+    words: Option<PathBuf>,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: simulate [--strategy overlap|entropy|minimax|frequency] [--start WORD] \
+         [--max-guesses N] [--limit N] [--patterns FILE] [--words FILE]"
+    );
+    exit(2)
+}
+
+fn parse_args() -> Args {
+    let mut args = Args {
+        scorer: Box::new(OverlapScorer),
+        start: None,
+        max_guesses: 6,
+        limit: None,
+        patterns: None,
+        words: None,
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        let value = iter.next().unwrap_or_else(|| usage());
+        match flag.as_str() {
+            "--strategy" => {
+                args.scorer = match value.as_str() {
+                    "overlap" => Box::new(OverlapScorer),
+                    "entropy" => Box::new(EntropyScorer),
+                    "minimax" => Box::new(MinimaxScorer),
+                    "frequency" => Box::new(FrequencyScorer),
+                    _ => usage(),
+                }
+            }
+            "--start" => args.start = Some(value),
+            "--max-guesses" => args.max_guesses = value.parse().unwrap_or_else(|_| usage()),
+            "--limit" => args.limit = Some(value.parse().unwrap_or_else(|_| usage())),
+            "--patterns" => args.patterns = Some(PathBuf::from(value)),
+            "--words" => args.words = Some(PathBuf::from(value)),
+            _ => usage(),
+        }
+    }
+
+    args
+}
+
+/// Loads the pattern table from `path`, or builds it for `guesses` and `answers` and saves it
+/// there.
+fn load_patterns(
+    path: &PathBuf,
+    guesses: &[&'static str],
+    answers: &[&'static str],
+) -> std::io::Result<PatternTable> {
+    if let Ok(file) = File::open(path) {
+        return PatternTable::read_from(BufReader::new(file));
+    }
+
+    eprintln!(
+        "building pattern table for {} guesses and {} answers...",
+        guesses.len(),
+        answers.len()
+    );
+    let table = PatternTable::new(guesses, answers);
+    table.write_to(BufWriter::new(File::create(path)?))?;
+    Ok(table)
+}
+
+/// Loads the dictionary from `path`, warning about any lines which had to be skipped.  The
+/// dictionary lives for the rest of the run, so it's leaked to let the engine borrow from it.
+fn load_words(path: &PathBuf) -> &'static Dictionary {
+    match Dictionary::load("en", path) {
+        Ok(dictionary) => {
+            for line in dictionary.invalid_lines() {
+                eprintln!(
+                    "{}:{}: skipping {:?}",
+                    path.display(),
+                    line.number,
+                    line.text
+                );
+            }
+            Box::leak(Box::new(dictionary))
+        }
+        Err(e) => {
+            eprintln!("couldn't load {}: {}", path.display(), e);
+            exit(1)
+        }
+    }
+}
+
+fn main() {
+    let args = parse_args();
+
+    let dictionary = match &args.words {
+        Some(path) => load_words(path),
+        None => Dictionary::get("en").unwrap(),
+    };
+    let guesses: Vec<_> = dictionary.words_of_len(5).collect();
+    if guesses.is_empty() {
+        eprintln!("there are no five letter words in the dictionary");
+        exit(1)
+    }
+
+    let start = args.start.map(|value| {
+        let word = value.to_lowercase();
+        match guesses.binary_search(&word.as_str()) {
+            Ok(i) => guesses[i],
+            Err(_) => {
+                eprintln!("{} is not a five letter word", value);
+                exit(2)
+            }
+        }
+    });
+
+    let mut engine = Engine::<5>::new(guesses.iter().copied()).scorer(args.scorer);
+    if args.words.is_none() {
+        engine = engine.answers(wordle_dict::ANSWERS.iter().copied());
+    }
+    if let Some(path) = &args.patterns {
+        match load_patterns(path, &guesses, &engine.candidates(&[])) {
+            Ok(table) => engine = engine.patterns(table),
+            Err(e) => {
+                eprintln!("couldn't load {}: {}", path.display(), e);
+                exit(1)
+            }
+        }
+    }
+
+    let mut answers = engine.candidates(&[]);
+    answers.sort_unstable();
+    if let Some(limit) = args.limit {
+        answers.truncate(limit);
+    }
+
+    // The opening guess doesn't depend on the answer, so only work it out once.
+    let start = start.or_else(|| simulate::best(&engine, &[]));
+    let report = simulate::simulate(&engine, &answers, args.max_guesses, |engine, inf| {
+        if inf.is_empty() {
+            start
+        } else {
+            simulate::best(engine, inf)
+        }
+    });
+
+    println!("answers:  {}", report.results.len());
+    println!("mean:     {:.4}", report.mean());
+    println!("failures: {}", report.failures().len());
+    println!();
+    for (guesses, count) in report.histogram() {
+        println!("{:>2}: {}", guesses, count);
+    }
+    println!();
+    println!("slowest:");
+    for (word, guesses) in report.slowest(10) {
+        println!("  {} ({})", word, guesses);
+    }
+}
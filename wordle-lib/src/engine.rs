@@ -1,70 +1,469 @@
-use crate::{Inference, Overlap};
-use std::collections::HashSet;
+use crate::alphabet::{Alphabet, English};
+use crate::error::check_word;
+use crate::explain::{self, Rejection};
+use crate::scorer::{Candidates, EntropyScorer, MinimaxScorer, OverlapScorer, Scorer};
+use crate::{hard_mode, overlap, par, Inference, Overlap, PatternTable, WordleError};
+use std::marker::PhantomData;
 
-pub struct Engine<const LEN: usize> {
-    words: Vec<&'static str>,
+/// How likely a word is to be the answer, relative to other words.
+type Prior = Box<dyn Fn(&str) -> f64 + Send + Sync>;
+
+/// Suggests guesses for words of a length chosen at runtime, from the alphabet `A`.
+///
+/// See [`Engine`] for an engine whose word length is fixed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{DynEngine, Inference, InferenceKind};
+///
+/// let words = ["bat", "cat", "cart", "dart"];
+/// let engine = DynEngine::new(4, words);
+///
+/// let inferences = [Inference::new('d', 0, InferenceKind::Correct)];
+/// assert_eq!(engine.word_len(), 4);
+/// assert_eq!(engine.candidates(&inferences), ["dart"]);
+/// ```
+pub struct DynEngine<'w, A: Alphabet = English> {
+    len: usize,
+    words: Vec<&'w str>,
+    /// The words which could be the answer, if narrower than `words`.
+    answers: Option<Vec<&'w str>>,
+    hard_mode: bool,
+    scorer: Box<dyn Scorer>,
+    /// How likely each word is to be the answer, relative to the others.
+    prior: Option<Prior>,
+    patterns: Option<PatternTable>,
+    alphabet: PhantomData<A>,
+}
+
+impl<'w> DynEngine<'w> {
+    /// Creates an engine from the words with `len` letters, ignoring the rest.  The words are
+    /// borrowed, so may be owned by a list loaded at runtime.
+    pub fn new<I, S>(len: usize, iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Self::new_in(len, iter)
+    }
+
+    /// Like [`DynEngine::new`], but returns an error if any word with `len` characters
+    /// contains something other than a letter.
+    pub fn try_new<I, S>(len: usize, iter: I) -> Result<Self, WordleError>
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Self::try_new_in(len, iter)
+    }
 }
 
-impl<const LEN: usize> Engine<LEN> {
-    pub fn new<I: IntoIterator<Item = &'static str>>(iter: I) -> Self {
+impl<'w, A: Alphabet> DynEngine<'w, A> {
+    /// Creates an engine for the alphabet `A` from the words with `len` characters, ignoring
+    /// the rest.  The words should already be written in the alphabet's lowercase letters.
+    pub fn new_in<I, S>(len: usize, iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
         Self {
-            words: iter.into_iter().filter(|w| w.len() == LEN).collect(),
+            len,
+            words: iter
+                .into_iter()
+                .map(|w| w.as_ref())
+                .filter(|w| w.chars().count() == len)
+                .collect(),
+            answers: None,
+            hard_mode: false,
+            scorer: Box::new(OverlapScorer),
+            prior: None,
+            patterns: None,
+            alphabet: PhantomData,
         }
     }
 
+    /// Like [`DynEngine::new_in`], but returns an error if any word with `len` characters
+    /// contains something other than a letter of `A`.
+    pub fn try_new_in<I, S>(len: usize, iter: I) -> Result<Self, WordleError>
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        let engine = Self::new_in(len, iter);
+        for word in &engine.words {
+            check_word::<A>(word, len)?;
+        }
+
+        Ok(engine)
+    }
+
+    /// The number of letters in each word.
+    pub fn word_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the engine knows no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Restricts the answer to the words in `iter` with the engine's length, rather than any word
+    /// the engine knows.  Every word can still be guessed, so [`DynEngine::rank_probes`] picks
+    /// from all of them while [`DynEngine::rank`] only picks from the answers.
+    pub fn answers<I, S>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        let len = self.len;
+        self.answers = Some(
+            iter.into_iter()
+                .map(|w| w.as_ref())
+                .filter(|w| w.chars().count() == len)
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets the strategy used by [`DynEngine::rank`] and [`DynEngine::rank_probes`].  Defaults to
+    /// [`OverlapScorer`].
+    pub fn scorer<S: Scorer + 'static>(mut self, scorer: S) -> Self {
+        self.scorer = Box::new(scorer);
+        self
+    }
+
+    /// Weights each candidate by how likely `prior` says it is to be the answer, relative to the
+    /// others, for scorers which take weights into account such as
+    /// [`EntropyScorer`](crate::scorer::EntropyScorer).  Guesses with equal scores are ranked by
+    /// their prior before being ranked alphabetically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wordle_lib::DynEngine;
+    ///
+    /// let engine = DynEngine::new(3, ["bat", "cat", "hat"]).prior(|w| if w == "cat" { 2.0 } else { 1.0 });
+    /// assert_eq!(engine.rank(&[])[0].0, "cat");
+    /// ```
+    pub fn prior<F>(mut self, prior: F) -> Self
+    where
+        F: Fn(&str) -> f64 + Send + Sync + 'static,
+    {
+        self.prior = Some(Box::new(prior));
+        self
+    }
+
+    /// Looks up feedback in `table` when ranking, rather than computing it.  Words missing from
+    /// the table are still scored, just more slowly.
+    pub fn patterns(mut self, table: PatternTable) -> Self {
+        self.patterns = Some(table);
+        self
+    }
+
+    /// Restricts probe guesses to those which use every revealed hint.
+    /// See [`hard_mode::check`] for details.
+    pub fn hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Lists the reasons `word` can't be the answer given the inferences so far, which is empty
+    /// if it still can be.  See [`explain::explain`] for details.
+    pub fn explain(&self, word: &str, inf: &[Inference<A>]) -> Vec<Rejection<A>> {
+        let word: String = word.chars().map(A::normalize).collect();
+        if !self.words.contains(&word.as_str()) {
+            return vec![Rejection::UnknownWord];
+        }
+
+        let mut reasons = Vec::new();
+        if !self.answer_list().contains(&word.as_str()) {
+            reasons.push(Rejection::NotAnAnswer);
+        }
+        reasons.extend(explain::explain(&word, inf));
+        reasons
+    }
+
+    /// Checks whether `guess` is a legal guess given the inferences so far.  Outside of hard
+    /// mode every guess is legal.
+    pub fn check_guess(
+        &self,
+        guess: &str,
+        inf: &[Inference<A>],
+    ) -> Result<(), hard_mode::Violation<A>> {
+        if self.hard_mode {
+            hard_mode::check(guess, inf)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Determines all words which are legal guesses given the inferences so far.
+    pub(crate) fn guesses(&self, inf: &[Inference<A>]) -> Vec<&'w str> {
+        par::filter(&self.words, |w| self.check_guess(w, inf).is_ok())
+    }
+
+    /// The words which could be the answer before any inferences are made.
+    fn answer_list(&self) -> &[&'w str] {
+        self.answers.as_deref().unwrap_or(&self.words)
+    }
+
+    /// Determines all answers consistent with every inference.
+    pub fn candidates(&self, inf: &[Inference<A>]) -> Vec<&'w str> {
+        par::filter(self.answer_list(), |&w| inf.iter().all(|f| f.filter(w)))
+    }
+
+    /// Determines all valid words, ranked best first by the engine's [`Scorer`].
+    /// Ties are broken alphabetically.
+    pub fn rank(&self, inf: &[Inference<A>]) -> Vec<(&'w str, f64)> {
+        let valid_words = self.candidates(inf);
+        self.rank_guesses(&valid_words, &valid_words)
+    }
+
+    /// Ranks every legal guess against the valid words, whether or not it could be the answer.
+    /// See [`DynEngine::probe`] for details.
+    pub fn rank_probes(&self, inf: &[Inference<A>]) -> Vec<(&'w str, f64)> {
+        let valid_words = self.candidates(inf);
+        self.rank_guesses(&self.guesses(inf), &valid_words)
+    }
+
+    pub(crate) fn rank_guesses(
+        &self,
+        guesses: &[&'w str],
+        valid_words: &[&'w str],
+    ) -> Vec<(&'w str, f64)> {
+        let scores = self.score_guesses(guesses, valid_words);
+        self.sort_ranked(guesses.iter().copied().zip(scores).collect())
+    }
+
+    /// Scores every guess against the valid words, in the same order as `guesses`.
+    pub(crate) fn score_guesses(&self, guesses: &[&str], valid_words: &[&str]) -> Vec<f64> {
+        self.score_with(&*self.scorer, guesses, valid_words)
+    }
+
+    /// Like [`DynEngine::score_guesses`], but with `scorer` rather than the engine's own.  The
+    /// engine's prior and pattern table are still used.
+    fn score_with(&self, scorer: &dyn Scorer, guesses: &[&str], valid_words: &[&str]) -> Vec<f64> {
+        let weights: Option<Vec<f64>> = self
+            .prior
+            .as_ref()
+            .map(|prior| valid_words.iter().map(|w| prior(w)).collect());
+
+        let mut candidates = match &self.patterns {
+            Some(table) => Candidates::with_patterns(valid_words, table),
+            None => Candidates::new(valid_words),
+        }
+        .alphabet::<A>();
+        if let Some(weights) = &weights {
+            candidates = candidates.weights(weights);
+        }
+
+        scorer.score_all(guesses, &candidates)
+    }
+
+    /// Sorts scored guesses best first, breaking ties by prior and then alphabetically.
+    pub(crate) fn sort_ranked<'a>(&self, ranked: Vec<(&'a str, f64)>) -> Vec<(&'a str, f64)> {
+        let prior = |w: &str| self.prior.as_ref().map_or(0.0, |prior| prior(w));
+        let mut ranked: Vec<_> = ranked.into_iter().map(|(w, s)| (w, s, prior(w))).collect();
+        ranked.sort_by(|(lw, l, lp), (rw, r, rp)| {
+            r.total_cmp(l).then(rp.total_cmp(lp)).then(lw.cmp(rw))
+        });
+        ranked.into_iter().map(|(w, s, _)| (w, s)).collect()
+    }
+
     /// Determines all valid words and the overlap scores between them.
-    pub fn evaluate(&self, inf: &[Inference]) -> Vec<(&'static str, Overlap)> {
-        let valid_words: Vec<_> = self
-            .words
+    pub fn evaluate(&self, inf: &[Inference<A>]) -> Vec<(&'w str, Overlap)> {
+        let valid_words = self.candidates(inf);
+        Self::overlaps(&valid_words, &valid_words)
+    }
+
+    /// Determines the overlap scores between every word known to the engine and the valid words.
+    ///
+    /// Unlike [`DynEngine::evaluate`], the guesses considered here need not be consistent with the
+    /// inferences: a word which cannot be the answer may still be the best way to narrow down
+    /// the remaining candidates.  In hard mode only legal guesses are considered.
+    pub fn probe(&self, inf: &[Inference<A>]) -> Vec<(&'w str, Overlap)> {
+        let valid_words = self.candidates(inf);
+        Self::overlaps(&self.guesses(inf), &valid_words)
+    }
+
+    fn overlaps(guesses: &[&'w str], valid_words: &[&'w str]) -> Vec<(&'w str, Overlap)> {
+        guesses
             .iter()
-            .filter(|&&w| inf.iter().all(|f| f.filter(w)))
             .copied()
-            .collect();
+            .zip(overlap::overlaps::<A, _, _>(guesses, valid_words))
+            .collect()
+    }
 
-        // We collect everything into two buckets: one based on "has this character"
-        // and one based on "has this character at this position".
-        let mut character_buckets: [HashSet<usize>; 26] =
-            array_init::array_init(|_| HashSet::new());
-        let mut positional_buckets: [[HashSet<usize>; 26]; LEN] =
-            array_init::array_init(|_| array_init::array_init(|_| HashSet::new()));
-
-        for (i, word) in valid_words.iter().enumerate() {
-            for (j, c) in lowercase_iter(word).enumerate() {
-                character_buckets[c].insert(i);
-                positional_buckets[j][c].insert(i);
-            }
-        }
+    /// Determines all valid words and the expected information, in bits, that guessing each of
+    /// them would reveal about the answer.  This is the score given by [`EntropyScorer`], so the
+    /// engine's prior is taken into account.
+    pub fn evaluate_entropy(&self, inf: &[Inference<A>]) -> Vec<(&'w str, f64)> {
+        let valid_words = self.candidates(inf);
+        self.entropies(&valid_words, &valid_words)
+    }
+
+    /// Determines the expected information, in bits, that guessing each word known to the engine
+    /// would reveal about the answer.  Like [`DynEngine::probe`], this includes words that are not
+    /// consistent with the inferences.
+    pub fn probe_entropy(&self, inf: &[Inference<A>]) -> Vec<(&'w str, f64)> {
+        let valid_words = self.candidates(inf);
+        self.entropies(&self.guesses(inf), &valid_words)
+    }
+
+    fn entropies(&self, guesses: &[&'w str], valid_words: &[&'w str]) -> Vec<(&'w str, f64)> {
+        let scores = self.score_with(&EntropyScorer, guesses, valid_words);
+        guesses.iter().copied().zip(scores).collect()
+    }
+
+    /// Determines all valid words, their overlap scores and the number of candidates that could
+    /// remain, in the worst case, after guessing each of them.  See
+    /// [`worst_case`](crate::worst_case) for details.
+    pub fn evaluate_minimax(&self, inf: &[Inference<A>]) -> Vec<(&'w str, Overlap, usize)> {
+        let valid_words = self.candidates(inf);
+        self.minimax(&valid_words, &valid_words)
+    }
 
-        valid_words
+    /// Determines the overlap scores and worst cases for every legal guess, whether or not it
+    /// could be the answer.  See [`DynEngine::probe`] for details.
+    pub fn probe_minimax(&self, inf: &[Inference<A>]) -> Vec<(&'w str, Overlap, usize)> {
+        let valid_words = self.candidates(inf);
+        self.minimax(&self.guesses(inf), &valid_words)
+    }
+
+    fn minimax(
+        &self,
+        guesses: &[&'w str],
+        valid_words: &[&'w str],
+    ) -> Vec<(&'w str, Overlap, usize)> {
+        // `MinimaxScorer` negates the worst case so that fewer is better.
+        let worst = self.score_with(&MinimaxScorer, guesses, valid_words);
+        Self::overlaps(guesses, valid_words)
             .into_iter()
-            .map(|w| {
-                let mut overlap = Overlap::default();
-                let mut seen = [false; 26];
-
-                for (i, c) in lowercase_iter(w).enumerate() {
-                    if !seen[c] {
-                        overlap.partial += character_buckets[c].len();
-                        seen[c] = true;
-                    }
-                    overlap.total += positional_buckets[i][c].len();
-                }
-
-                (w, overlap)
-            })
+            .zip(worst)
+            .map(|((w, overlap), worst)| (w, overlap, -worst as usize))
             .collect()
     }
 }
 
-fn lowercase_iter(w: &'static str) -> impl Iterator<Item = usize> {
-    w.chars()
-        .filter(|c| c.is_ascii_alphabetic())
-        .map(|c| ((c.to_ascii_lowercase() as u8) - b'a') as usize)
+/// Suggests guesses for words of `LEN` letters from the alphabet `A`.
+///
+/// This is a [`DynEngine`] whose word length is fixed at compile time, and dereferences to one
+/// for everything other than construction.
+pub struct Engine<'w, const LEN: usize, A: Alphabet = English> {
+    inner: DynEngine<'w, A>,
+}
+
+impl<'w, const LEN: usize> Engine<'w, LEN> {
+    /// Creates an engine from the words with `LEN` letters, ignoring the rest.
+    pub fn new<I, S>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Self::new_in(iter)
+    }
+
+    /// Like [`Engine::new`], but returns an error if any word with `LEN` characters contains
+    /// something other than a letter.
+    pub fn try_new<I, S>(iter: I) -> Result<Self, WordleError>
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Self::try_new_in(iter)
+    }
+}
+
+impl<'w, const LEN: usize, A: Alphabet> Engine<'w, LEN, A> {
+    /// Creates an engine for the alphabet `A` from the words with `LEN` characters, ignoring
+    /// the rest.  The words should already be written in the alphabet's lowercase letters.
+    pub fn new_in<I, S>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Self {
+            inner: DynEngine::new_in(LEN, iter),
+        }
+    }
+
+    /// Like [`Engine::new_in`], but returns an error if any word with `LEN` characters contains
+    /// something other than a letter of `A`.
+    pub fn try_new_in<I, S>(iter: I) -> Result<Self, WordleError>
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Ok(Self {
+            inner: DynEngine::try_new_in(LEN, iter)?,
+        })
+    }
+
+    /// See [`DynEngine::answers`].
+    pub fn answers<I, S>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'w S>,
+        S: AsRef<str> + ?Sized + 'w,
+    {
+        Self {
+            inner: self.inner.answers(iter),
+        }
+    }
+
+    /// See [`DynEngine::prior`].
+    pub fn prior<F>(self, prior: F) -> Self
+    where
+        F: Fn(&str) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            inner: self.inner.prior(prior),
+        }
+    }
+
+    /// See [`DynEngine::scorer`].
+    pub fn scorer<S: Scorer + 'static>(self, scorer: S) -> Self {
+        Self {
+            inner: self.inner.scorer(scorer),
+        }
+    }
+
+    /// See [`DynEngine::patterns`].
+    pub fn patterns(self, table: PatternTable) -> Self {
+        Self {
+            inner: self.inner.patterns(table),
+        }
+    }
+
+    /// See [`DynEngine::hard_mode`].
+    pub fn hard_mode(self, hard_mode: bool) -> Self {
+        Self {
+            inner: self.inner.hard_mode(hard_mode),
+        }
+    }
+
+    /// Converts this into an engine whose word length is only known at runtime.
+    pub fn into_dyn(self) -> DynEngine<'w, A> {
+        self.inner
+    }
+}
+
+impl<'w, const LEN: usize, A: Alphabet> std::ops::Deref for Engine<'w, LEN, A> {
+    type Target = DynEngine<'w, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Engine;
-    use crate::{Inference, InferenceKind, Overlap};
+    use super::{DynEngine, Engine};
+    use crate::alphabet::Spanish;
+    use crate::explain::Rejection;
+    use crate::scorer::{EntropyScorer, MinimaxScorer};
+    use crate::{Inference, InferenceKind, Overlap, PatternTable, WordleError};
+    use std::sync::Arc;
 
     #[test]
     fn inference_engine() {
@@ -72,7 +471,7 @@ mod tests {
         let engine = Engine::<5>::new(words);
 
         // Filter out any words that don't have an `a` in them.
-        let words = engine.evaluate(&vec![Inference::new('a', 2, InferenceKind::Present)]);
+        let words = engine.evaluate(&[Inference::new('a', 2, InferenceKind::Present)]);
         assert_eq!(words.len(), 4);
 
         // Overlaps:
@@ -95,4 +494,273 @@ mod tests {
         //
         assert_eq!(words[1], ("banan", Overlap::new(6, 6)));
     }
+
+    #[test]
+    fn probe_engine() {
+        let words = ["bat", "cat", "hat", "mat", "chm"];
+        let engine = Engine::<3>::new(words);
+
+        // Only the words ending in `at` remain, so `chm` isn't a valid answer...
+        let inferences = [
+            Inference::new('a', 1, InferenceKind::Correct),
+            Inference::new('t', 2, InferenceKind::Correct),
+        ];
+        assert_eq!(engine.evaluate(&inferences).len(), 4);
+
+        // ...but it is still scored as a probe, and is the only guess that can tell
+        // all four apart.
+        let probes = engine.probe_entropy(&inferences);
+        assert_eq!(probes.len(), 5);
+
+        let (best, _) = probes
+            .iter()
+            .copied()
+            .max_by(|(_, l), (_, r)| l.partial_cmp(r).unwrap())
+            .unwrap();
+        assert_eq!(best, "chm");
+
+        // Overlaps for `chm`: one positional match with `cat`, and one letter in common
+        // with each of `cat`, `hat` and `mat`.
+        let probes = engine.probe(&inferences);
+        assert_eq!(probes[4], ("chm", Overlap::new(1, 3)));
+    }
+
+    #[test]
+    fn hard_mode_engine() {
+        let words = ["bat", "cat", "hat", "mat", "chm"];
+        let inferences = [
+            Inference::new('a', 1, InferenceKind::Correct),
+            Inference::new('t', 2, InferenceKind::Correct),
+        ];
+
+        // `chm` doesn't keep the correct letters, so it can't be played in hard mode.
+        let engine = Engine::<3>::new(words).hard_mode(true);
+        assert!(engine.check_guess("bat", &inferences).is_ok());
+        assert!(engine.check_guess("chm", &inferences).is_err());
+        assert_eq!(engine.probe(&inferences).len(), 4);
+        assert_eq!(engine.probe_entropy(&inferences).len(), 4);
+
+        let engine = Engine::<3>::new(words);
+        assert!(engine.check_guess("chm", &inferences).is_ok());
+        assert_eq!(engine.probe(&inferences).len(), 5);
+    }
+
+    #[test]
+    fn try_new_engine() {
+        assert!(Engine::<3>::try_new(["bat", "cat", "bats"]).is_ok());
+        assert_eq!(
+            Engine::<3>::try_new(["bat", "c4t"]).err(),
+            Some(WordleError::InvalidLetter('4'))
+        );
+
+        // Lengths are counted in characters, not bytes.
+        assert_eq!(Engine::<3>::new(["été", "bat"]).candidates(&[]).len(), 2);
+    }
+
+    #[test]
+    fn alphabet_engine() {
+        let words = ["niño", "niña", "caña", "cana", "mesa"];
+        let engine = Engine::<4, Spanish>::new_in(words);
+
+        // `ñ` and `n` are different letters, but accents on vowels are ignored.
+        let inferences = [
+            Inference::new_in('Ñ', 2, InferenceKind::Correct),
+            Inference::new_in('á', 3, InferenceKind::Correct),
+        ];
+        assert_eq!(engine.candidates(&inferences), ["niña", "caña"]);
+        assert!(engine.explain("CAÑA", &inferences).is_empty());
+        assert_eq!(engine.rank(&inferences).len(), 2);
+
+        assert!(Engine::<4, Spanish>::try_new_in(words).is_ok());
+        assert!(Engine::<4>::try_new(words).is_err());
+    }
+
+    #[test]
+    fn dyn_engine() {
+        let words = ["bat", "cat", "hat", "dot", "cart", "dart", "darts"];
+        let inferences = [Inference::new('a', 1, InferenceKind::Correct)];
+
+        for len in 3..=5 {
+            let engine = DynEngine::new(len, words);
+            assert_eq!(engine.word_len(), len);
+            assert!(engine
+                .candidates(&[])
+                .iter()
+                .all(|w| w.chars().count() == len));
+        }
+
+        // The const-generic engine gives the same answers as the runtime one.
+        let fixed = Engine::<4>::new(words).scorer(EntropyScorer);
+        let dynamic = DynEngine::new(4, words).scorer(EntropyScorer);
+        assert_eq!(fixed.rank(&inferences), dynamic.rank(&inferences));
+        assert_eq!(fixed.into_dyn().word_len(), 4);
+
+        let empty = DynEngine::new(6, words);
+        assert!(empty.is_empty());
+        assert_eq!(empty.word_len(), 6);
+        assert!(DynEngine::try_new(3, ["bat", "c4t"]).is_err());
+    }
+
+    #[test]
+    fn owned_words_engine() {
+        let inferences = [Inference::new('a', 1, InferenceKind::Correct)];
+
+        let owned: Vec<String> = ["bat", "cat", "dot"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let engine = Engine::<3>::new(&owned);
+        assert_eq!(engine.candidates(&inferences), ["bat", "cat"]);
+
+        let shared: Vec<Arc<str>> = ["bat", "cat", "dot"].iter().map(|&w| w.into()).collect();
+        let engine = DynEngine::new(3, &shared);
+        assert_eq!(engine.rank(&inferences).len(), 2);
+    }
+
+    #[test]
+    fn explain_engine() {
+        let engine = Engine::<3>::new(["bat", "cat", "hat", "dot"]);
+        let inferences = [
+            Inference::new('c', 0, InferenceKind::AbsentGlobal),
+            Inference::new('a', 1, InferenceKind::Correct),
+        ];
+
+        assert!(engine.explain("BAT", &inferences).is_empty());
+        assert_eq!(
+            engine.explain("cat", &inferences),
+            [Rejection::Inference(inferences[0])]
+        );
+        assert_eq!(
+            engine.explain("dot", &inferences),
+            [Rejection::Inference(inferences[1])]
+        );
+        assert_eq!(
+            engine.explain("bats", &inferences),
+            [Rejection::UnknownWord]
+        );
+    }
+
+    #[test]
+    fn answers_engine() {
+        let engine = Engine::<3>::new(["bat", "cat", "hat", "cwm", "oat"]).answers(["bat", "hat"]);
+        let inferences = [Inference::new('b', 0, InferenceKind::AbsentGlobal)];
+
+        assert_eq!(engine.candidates(&[]), ["bat", "hat"]);
+        assert_eq!(engine.rank(&inferences)[0].0, "hat");
+        assert_eq!(engine.rank(&inferences).len(), 1);
+        assert_eq!(engine.rank_probes(&inferences).len(), 5);
+
+        assert_eq!(engine.explain("cat", &inferences), [Rejection::NotAnAnswer]);
+        assert_eq!(
+            engine.explain("bat", &inferences),
+            [Rejection::Inference(inferences[0])]
+        );
+    }
+
+    #[test]
+    fn prior_engine() {
+        let words = ["bat", "cat", "hat", "dot"];
+        let prior = |w: &str| if w == "hat" { 2.0 } else { 1.0 };
+
+        // Ties are broken by the prior before the alphabet.
+        let engine = Engine::<3>::new(words).prior(prior);
+        let ranked: Vec<_> = engine.rank(&[]).into_iter().map(|(w, _)| w).collect();
+        assert_eq!(ranked, ["hat", "bat", "cat", "dot"]);
+
+        // The prior weights candidates when scoring by entropy.
+        let engine = Engine::<3>::new(words).scorer(EntropyScorer);
+        let weighted = Engine::<3>::new(words).scorer(EntropyScorer).prior(|w| {
+            if w == "dot" {
+                100.0
+            } else {
+                1.0
+            }
+        });
+        let score = |ranked: Vec<(&str, f64)>| {
+            ranked
+                .into_iter()
+                .find_map(|(w, score)| (w == "bat").then_some(score))
+                .unwrap()
+        };
+        assert!(score(weighted.rank(&[])) < score(engine.rank(&[])));
+
+        // `evaluate_entropy` agrees with the scorer, prior and all.
+        let mut evaluated = weighted.evaluate_entropy(&[]);
+        evaluated.sort_by_key(|&(w, _)| w);
+        let mut ranked = weighted.rank(&[]);
+        ranked.sort_by_key(|&(w, _)| w);
+        assert_eq!(evaluated, ranked);
+    }
+
+    #[test]
+    fn minimax_engine() {
+        let words = ["bat", "cat", "hat", "dot", "chb"];
+        let engine = Engine::<3>::new(words);
+
+        // Only words ending in `t` remain.
+        let inferences = [Inference::new('t', 2, InferenceKind::Correct)];
+        let words = engine.evaluate_minimax(&inferences);
+        assert_eq!(words.len(), 4);
+        assert_eq!(words[0], ("bat", Overlap::new(8, 8), 2));
+        assert_eq!(words[3], ("dot", Overlap::new(6, 6), 3));
+
+        // `chb` can't be the answer, but it does tell all four candidates apart.
+        let probes = engine.probe_minimax(&inferences);
+        assert_eq!(probes.len(), 5);
+        assert_eq!(probes[4], ("chb", Overlap::new(1, 3), 1));
+    }
+
+    #[test]
+    fn rank_engine() {
+        let words = ["bat", "cat", "hat", "dot", "chb"];
+        let inferences = [Inference::new('t', 2, InferenceKind::Correct)];
+
+        // By default, words are ranked by overlap.
+        let engine = Engine::<3>::new(words);
+        let ranked = engine.rank(&inferences);
+        assert_eq!(ranked.len(), 4);
+        assert_eq!(ranked[0], ("bat", 10.0));
+        assert_eq!(ranked[3], ("dot", 8.0));
+
+        // `chb` tells all four candidates apart.
+        let engine = Engine::<3>::new(words).scorer(MinimaxScorer);
+        let ranked = engine.rank_probes(&inferences);
+        assert_eq!(ranked.len(), 5);
+        assert_eq!(ranked[0], ("chb", -1.0));
+        assert_eq!(ranked[1], ("bat", -2.0));
+    }
+
+    #[test]
+    fn patterns_engine() {
+        let words = ["bat", "cat", "hat", "dot", "chb"];
+        let inferences = [Inference::new('t', 2, InferenceKind::Correct)];
+        let table = PatternTable::new(&words, &words);
+
+        let engine = Engine::<3>::new(words).scorer(EntropyScorer);
+        let expected = engine.rank_probes(&inferences);
+
+        let engine = Engine::<3>::new(words)
+            .scorer(EntropyScorer)
+            .patterns(table);
+        assert_eq!(engine.rank_probes(&inferences), expected);
+        assert_eq!(expected[0].0, "chb");
+    }
+
+    #[test]
+    fn entropy_engine() {
+        let words = ["bat", "cat", "hat", "dot", "dog"];
+        let engine = Engine::<3>::new(words);
+
+        // Only words containing a `t` remain.
+        let words = engine.evaluate_entropy(&[Inference::new('t', 0, InferenceKind::AtLeast(1))]);
+        assert_eq!(words.len(), 4);
+
+        // `bat` can't tell `cat` and `hat` apart, so its buckets have sizes 1, 2 and 1.
+        assert_eq!(words[0].0, "bat");
+        assert!((words[0].1 - 1.5).abs() < 1e-9);
+
+        // `dot` can only tell whether the answer is `dot` or not.
+        assert_eq!(words[3].0, "dot");
+        assert!((words[3].1 - 0.811278).abs() < 1e-6);
+    }
 }
@@ -0,0 +1,96 @@
+use crate::alphabet::{Alphabet, English};
+use crate::feedback::partition_in;
+
+/// Computes the expected information, in bits, gained by guessing `guess` when the answer is
+/// equally likely to be any of the `candidates`.
+///
+/// The candidates are partitioned by the feedback `guess` would receive against each of them;
+/// a guess that splits the candidates into many small buckets scores highly, while a guess that
+/// cannot distinguish between any of them scores zero.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::entropy;
+///
+/// // `cat` tells all four words apart, while `zip` can't tell any of them apart.
+/// let words = ["cat", "dog", "cut", "hat"];
+///
+/// assert_eq!(entropy("cat", &words), 2.0);
+/// assert_eq!(entropy("zip", &words), 0.0);
+/// ```
+pub fn entropy<S: AsRef<str>>(guess: &str, candidates: &[S]) -> f64 {
+    entropy_in::<English, _>(guess, candidates)
+}
+
+/// Like [`entropy`], for words in the alphabet `A`.
+pub fn entropy_in<A: Alphabet, S: AsRef<str>>(guess: &str, candidates: &[S]) -> f64 {
+    let buckets: Vec<_> = partition_in::<A, _>(guess, candidates)
+        .into_values()
+        .collect();
+    from_buckets(&buckets)
+}
+
+/// Computes the expected information, in bits, of a guess which partitions the candidates into
+/// buckets of the given sizes.
+pub(crate) fn from_buckets(buckets: &[usize]) -> f64 {
+    let buckets: Vec<_> = buckets.iter().map(|&n| n as f64).collect();
+    from_weights(&buckets)
+}
+
+/// Like [`from_buckets`], but each bucket is the total weight of the candidates in it.
+///
+/// The buckets are summed smallest first, so guesses which split the candidates the same way
+/// score exactly the same whatever order their buckets come in.
+pub(crate) fn from_weights(buckets: &[f64]) -> f64 {
+    let mut buckets = buckets.to_vec();
+    buckets.sort_unstable_by(f64::total_cmp);
+    let total = buckets.iter().sum::<f64>();
+
+    buckets
+        .iter()
+        .map(|&w| {
+            let p = w / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::entropy;
+
+    #[test]
+    fn entropy_empty() {
+        let words: [&str; 0] = [];
+        assert_eq!(entropy("cat", &words), 0.0);
+    }
+
+    #[test]
+    fn entropy_uneven_split() {
+        // `cat` scores [C, C, C] against `cat`, [C, A, C] against both `cot` and `cut`
+        // and [A, C, C] against `hat`; so the buckets have sizes 1, 2 and 1.
+        let words = ["cat", "cot", "cut", "hat"];
+        assert!((entropy("cat", &words) - 1.5).abs() < 1e-9);
+
+        // Two buckets of two words each.
+        let words = ["cot", "cut", "hat", "bat"];
+        assert!((entropy("cat", &words) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn entropy_is_deterministic() {
+        let answers: Vec<_> = wordle_dict::ANSWERS.iter().copied().collect();
+        let mut reversed = answers.clone();
+        reversed.reverse();
+
+        // The same buckets in any order give bitwise-identical scores.
+        for &guess in answers.iter().take(200) {
+            assert_eq!(entropy(guess, &answers), entropy(guess, &reversed));
+        }
+        assert_eq!(
+            entropy("abc", &["abd", "xyz"]),
+            entropy("abc", &["xyz", "abd"])
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::alphabet::Alphabet;
+
+/// The ways words and inferences can be malformed.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WordleError {
+    /// A word or inference contains something other than a letter.
+    InvalidLetter(char),
+    /// An inference refers to a position past the end of the word.
+    PositionOutOfRange { position: usize, len: usize },
+    /// A word has a different number of letters than expected.
+    WrongLength { expected: usize, found: usize },
+}
+
+impl std::fmt::Display for WordleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordleError::InvalidLetter(c) => write!(f, "{:?} is not a letter", c),
+            WordleError::PositionOutOfRange { position, len } => write!(
+                f,
+                "position {} is out of range for a word of length {}",
+                position, len
+            ),
+            WordleError::WrongLength { expected, found } => {
+                write!(f, "expected {} letters, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
+
+/// Checks that `word` is made up of exactly `len` letters of the alphabet `A`.
+pub(crate) fn check_word<A: Alphabet>(word: &str, len: usize) -> Result<(), WordleError> {
+    if let Some(c) = word.chars().find(|&c| A::fold(c).is_none()) {
+        return Err(WordleError::InvalidLetter(c));
+    }
+
+    let found = word.chars().count();
+    if found != len {
+        return Err(WordleError::WrongLength {
+            expected: len,
+            found,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::alphabet::{Alphabet, English};
+use crate::{Inference, InferenceKind};
+
+/// A reason a word can't be the answer.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub enum Rejection<A: Alphabet = English> {
+    /// The word isn't in the word list.
+    UnknownWord,
+    /// The word can be guessed, but isn't in the answer list.
+    NotAnAnswer,
+    /// The word doesn't satisfy an inference.
+    Inference(Inference<A>),
+}
+
+impl<A: Alphabet> std::fmt::Display for Rejection<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inference = match self {
+            Rejection::UnknownWord => return write!(f, "not in the word list"),
+            Rejection::NotAnAnswer => return write!(f, "not in the answer list"),
+            Rejection::Inference(inference) => inference,
+        };
+
+        let c = inference.char().to_uppercase();
+        let position = inference.position() + 1;
+        match inference.kind() {
+            InferenceKind::AbsentGlobal => write!(f, "answer can't contain {}", c),
+            InferenceKind::AbsentLocal => write!(f, "letter {} can't be {}", position, c),
+            InferenceKind::Present => write!(
+                f,
+                "answer must contain {}, but not as letter {}",
+                c, position
+            ),
+            InferenceKind::Correct => write!(f, "letter {} must be {}", position, c),
+            InferenceKind::Count(1) => write!(f, "answer must contain exactly one {}", c),
+            InferenceKind::Count(n) => write!(f, "answer must contain exactly {} {}s", n, c),
+            InferenceKind::AtLeast(n) => write!(f, "answer must contain at least {} {}s", n, c),
+        }
+    }
+}
+
+/// Lists every inference which rules out `word`, in the order given.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{explain, Inference, InferenceKind};
+///
+/// let inferences = [
+///     Inference::new('r', 1, InferenceKind::Correct),
+///     Inference::new('e', 0, InferenceKind::AbsentGlobal),
+/// ];
+///
+/// let rejections = explain::explain("tread", &inferences);
+/// assert_eq!(rejections.len(), 1);
+/// assert_eq!(rejections[0].to_string(), "answer can't contain E");
+///
+/// assert!(explain::explain("brick", &inferences).is_empty());
+/// ```
+pub fn explain<A: Alphabet>(word: &str, inf: &[Inference<A>]) -> Vec<Rejection<A>> {
+    inf.iter()
+        .filter(|i| !i.filter(word))
+        .map(|&i| Rejection::Inference(i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explain, Rejection};
+    use crate::{Inference, InferenceKind};
+
+    #[test]
+    fn explain_reasons() {
+        let inferences = [
+            Inference::new('a', 1, InferenceKind::Present),
+            Inference::new('t', 2, InferenceKind::Correct),
+            Inference::new('o', 0, InferenceKind::Count(1)),
+        ];
+
+        assert_eq!(
+            explain("cat", &inferences),
+            [
+                Rejection::Inference(inferences[0]),
+                Rejection::Inference(inferences[2])
+            ]
+        );
+        assert!(explain("aot", &inferences).is_empty());
+
+        let reasons: Vec<_> = explain("boo", &inferences)
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                "answer must contain A, but not as letter 2",
+                "letter 3 must be T",
+                "answer must contain exactly one O"
+            ]
+        );
+    }
+}
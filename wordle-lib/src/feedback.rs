@@ -0,0 +1,444 @@
+use crate::alphabet::{Alphabet, English};
+use crate::{Inference, InferenceKind, WordleError};
+use std::collections::{BTreeMap, HashMap};
+
+/// The colour a single tile receives when a guess is scored against an answer.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mark {
+    /// The character does not appear in the answer (or every occurrence of it has
+    /// already been accounted for by other tiles).
+    Absent,
+    /// The character appears in the answer, but not at this position.
+    Present,
+    /// The character appears in the answer at this position.
+    Correct,
+}
+
+/// The pattern of marks a guess receives when scored against an answer.
+///
+/// Duplicate letters follow the standard rules: correct tiles are assigned first, and the
+/// remaining occurrences of each letter in the answer are handed out as present tiles from
+/// left to right.  Any further copies of a letter in the guess are marked absent.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{Feedback, Mark};
+///
+/// let feedback = Feedback::score("speed", "abide");
+///
+/// assert_eq!(
+///     feedback.marks(),
+///     &[Mark::Absent, Mark::Absent, Mark::Present, Mark::Absent, Mark::Present]
+/// );
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Feedback {
+    marks: Vec<Mark>,
+}
+
+impl From<Vec<Mark>> for Feedback {
+    fn from(marks: Vec<Mark>) -> Self {
+        Self::new(marks)
+    }
+}
+
+/// Writes each tile as `G` (correct), `Y` (present) or `B` (absent).
+impl std::fmt::Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for mark in &self.marks {
+            let c = match mark {
+                Mark::Absent => 'B',
+                Mark::Present => 'Y',
+                Mark::Correct => 'G',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl Feedback {
+    pub fn new(marks: Vec<Mark>) -> Self {
+        Self { marks }
+    }
+
+    /// Scores `guess` against `answer`.
+    ///
+    /// Both words are compared case-insensitively and must have the same length.
+    pub fn score<G: AsRef<str>, W: AsRef<str>>(guess: G, answer: W) -> Self {
+        Self::score_in::<English, _, _>(guess, answer)
+    }
+
+    /// Like [`Feedback::score`], for words in the alphabet `A`.  Characters are compared once
+    /// folded into the alphabet's letters, so with [`Spanish`](crate::alphabet::Spanish) an `á`
+    /// in the guess matches an `a` in the answer.
+    pub fn score_in<A: Alphabet, G: AsRef<str>, W: AsRef<str>>(guess: G, answer: W) -> Self {
+        Self::_score::<A>(guess.as_ref(), answer.as_ref())
+    }
+
+    /// Like [`Feedback::score`], but returns an error rather than panicking if the words have
+    /// different lengths.
+    pub fn try_score<G: AsRef<str>, W: AsRef<str>>(
+        guess: G,
+        answer: W,
+    ) -> Result<Self, WordleError> {
+        Self::try_score_in::<English, _, _>(guess, answer)
+    }
+
+    /// Like [`Feedback::try_score`], for words in the alphabet `A`.
+    pub fn try_score_in<A: Alphabet, G: AsRef<str>, W: AsRef<str>>(
+        guess: G,
+        answer: W,
+    ) -> Result<Self, WordleError> {
+        let (guess, answer) = (guess.as_ref(), answer.as_ref());
+        let expected = answer.chars().count();
+        let found = guess.chars().count();
+        if found != expected {
+            return Err(WordleError::WrongLength { expected, found });
+        }
+
+        Ok(Self::_score::<A>(guess, answer))
+    }
+
+    fn _score<A: Alphabet>(guess: &str, answer: &str) -> Self {
+        let guess: Vec<char> = guess.chars().map(A::normalize).collect();
+        let answer: Vec<char> = answer.chars().map(A::normalize).collect();
+        assert_eq!(guess.len(), answer.len());
+
+        let mut marks = vec![Mark::Absent; guess.len()];
+
+        // Assign all the correct tiles first, keeping track of which characters in
+        // the answer are still available to be marked as present.
+        let mut unmatched: HashMap<char, usize> = HashMap::new();
+        for (i, (&g, &a)) in guess.iter().zip(answer.iter()).enumerate() {
+            if g == a {
+                marks[i] = Mark::Correct;
+            } else {
+                *unmatched.entry(a).or_default() += 1;
+            }
+        }
+
+        for (i, g) in guess.iter().enumerate() {
+            if marks[i] == Mark::Correct {
+                continue;
+            }
+            if let Some(remaining) = unmatched.get_mut(g).filter(|n| **n > 0) {
+                *remaining -= 1;
+                marks[i] = Mark::Present;
+            }
+        }
+
+        Self { marks }
+    }
+
+    pub fn marks(&self) -> &[Mark] {
+        &self.marks
+    }
+
+    /// Returns `true` if every tile is correct.
+    pub fn is_solved(&self) -> bool {
+        self.marks.iter().all(|&m| m == Mark::Correct)
+    }
+
+    /// Encodes the pattern as a base three number, with the first tile as the most
+    /// significant digit.  Two patterns of the same length have the same code if and only
+    /// if they are equal.
+    pub fn code(&self) -> usize {
+        self.marks.iter().fold(0, |code, &m| {
+            code * 3
+                + match m {
+                    Mark::Absent => 0,
+                    Mark::Present => 1,
+                    Mark::Correct => 2,
+                }
+        })
+    }
+
+    /// Converts this pattern, as received by `guess`, into the inferences it implies about
+    /// the answer.
+    pub fn inferences<G: AsRef<str>>(&self, guess: G) -> Vec<Inference> {
+        self.inferences_in(guess)
+    }
+
+    /// Like [`Feedback::inferences`], for a guess in the alphabet `A`.
+    pub fn inferences_in<A: Alphabet, G: AsRef<str>>(&self, guess: G) -> Vec<Inference<A>> {
+        let guess = guess.as_ref();
+        assert_eq!(guess.chars().count(), self.marks.len());
+
+        infer_in(
+            guess
+                .chars()
+                .zip(self.marks.iter().copied())
+                .enumerate()
+                .map(|(i, (c, m))| (i, c, m)),
+        )
+    }
+}
+
+/// Determines the inferences implied by a (possibly incomplete) scored guess, given as
+/// `(position, character, mark)` triples.
+///
+/// Every tile produces a positional inference.  Each distinct character additionally
+/// produces a bound on the number of times it appears in the answer: an exact count if any
+/// of its tiles were absent, a lower bound if none were, and [`InferenceKind::AbsentGlobal`]
+/// if all of them were.
+pub fn infer<I: IntoIterator<Item = (usize, char, Mark)>>(tiles: I) -> Vec<Inference> {
+    infer_in(tiles)
+}
+
+/// Like [`infer`], for a guess in the alphabet `A`.
+pub fn infer_in<A: Alphabet, I: IntoIterator<Item = (usize, char, Mark)>>(
+    tiles: I,
+) -> Vec<Inference<A>> {
+    let mut inferences = Vec::new();
+
+    // (correct, present, absent) tiles for each character.
+    let mut states: BTreeMap<char, (usize, usize, usize)> = BTreeMap::new();
+
+    for (i, c, mark) in tiles {
+        let c = A::normalize(c);
+        let state = states.entry(c).or_default();
+
+        match mark {
+            Mark::Correct => {
+                inferences.push(Inference::new_in(c, i, InferenceKind::Correct));
+                state.0 += 1;
+            }
+            Mark::Present => {
+                inferences.push(Inference::new_in(c, i, InferenceKind::Present));
+                state.1 += 1;
+            }
+            Mark::Absent => {
+                inferences.push(Inference::new_in(c, i, InferenceKind::AbsentLocal));
+                state.2 += 1;
+            }
+        }
+    }
+
+    for (c, (correct, present, absent)) in states {
+        let kind = if correct + present > 0 {
+            if absent > 0 {
+                InferenceKind::Count(correct + present)
+            } else {
+                InferenceKind::AtLeast(correct + present)
+            }
+        } else {
+            InferenceKind::AbsentGlobal
+        };
+        inferences.push(Inference::new_in(c, 0, kind));
+    }
+
+    inferences
+}
+
+/// The longest word [`pattern`] scores without allocating.
+const MAX_FAST_LEN: usize = 32;
+
+/// Computes `Feedback::score_in::<A, _, _>(guess, answer).code()`, avoiding allocation for
+/// short words.
+pub(crate) fn pattern<A: Alphabet>(guess: &str, answer: &str) -> usize {
+    let mut g = ['\0'; MAX_FAST_LEN];
+    let mut a = ['\0'; MAX_FAST_LEN];
+    let mut len = 0;
+
+    for (gc, ac) in guess.chars().zip(answer.chars()) {
+        if len == MAX_FAST_LEN {
+            return Feedback::score_in::<A, _, _>(guess, answer).code();
+        }
+        g[len] = A::normalize(gc);
+        a[len] = A::normalize(ac);
+        len += 1;
+    }
+
+    let mut marks = [0; MAX_FAST_LEN];
+    let mut used = [false; MAX_FAST_LEN];
+
+    for i in 0..len {
+        if g[i] == a[i] {
+            marks[i] = 2;
+            used[i] = true;
+        }
+    }
+
+    for i in 0..len {
+        if marks[i] == 2 {
+            continue;
+        }
+        if let Some(j) = (0..len).find(|&j| !used[j] && a[j] == g[i]) {
+            used[j] = true;
+            marks[i] = 1;
+        }
+    }
+
+    marks[..len].iter().fold(0, |code, &m| code * 3 + m)
+}
+
+/// Partitions `candidates` by the feedback `guess` would receive against each of them,
+/// returning the size of each non-empty bucket keyed by [`Feedback::code`].
+pub fn partition<S: AsRef<str>>(guess: &str, candidates: &[S]) -> BTreeMap<usize, usize> {
+    partition_in::<English, _>(guess, candidates)
+}
+
+/// Like [`partition`], for words in the alphabet `A`.
+pub fn partition_in<A: Alphabet, S: AsRef<str>>(
+    guess: &str,
+    candidates: &[S],
+) -> BTreeMap<usize, usize> {
+    partition_with(pattern::<A>, guess, candidates)
+}
+
+/// Like [`partition`], computing the feedback codes with `pattern`.
+pub(crate) fn partition_with<S: AsRef<str>>(
+    pattern: fn(&str, &str) -> usize,
+    guess: &str,
+    candidates: &[S],
+) -> BTreeMap<usize, usize> {
+    let mut buckets = BTreeMap::new();
+    for candidate in candidates {
+        *buckets
+            .entry(pattern(guess, candidate.as_ref()))
+            .or_default() += 1;
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{partition, partition_in, pattern, Feedback, Mark};
+    use crate::alphabet::{English, Spanish};
+    use crate::{Inference, InferenceKind, WordleError};
+
+    use Mark::{Absent as A, Correct as C, Present as P};
+
+    #[test]
+    fn feedback_score() {
+        assert_eq!(Feedback::score("crane", "crane").marks(), &[C, C, C, C, C]);
+        assert_eq!(Feedback::score("crane", "moist").marks(), &[A, A, A, A, A]);
+        assert_eq!(Feedback::score("CRANE", "react").marks(), &[P, P, C, A, P]);
+    }
+
+    #[test]
+    fn feedback_try_score() {
+        assert_eq!(
+            Feedback::try_score("crane", "react"),
+            Ok(Feedback::score("crane", "react"))
+        );
+        assert_eq!(
+            Feedback::try_score("cranes", "react"),
+            Err(WordleError::WrongLength {
+                expected: 5,
+                found: 6
+            })
+        );
+    }
+
+    #[test]
+    fn feedback_score_duplicates() {
+        // Only one `e` in the answer, so only the first `e` is present.
+        assert_eq!(Feedback::score("speed", "abide").marks(), &[A, A, P, A, P]);
+
+        // A correct `l` uses up an occurrence before any present tiles are handed out.
+        assert_eq!(Feedback::score("lolly", "hello").marks(), &[A, P, C, C, A]);
+
+        // Two `e`s in the answer, so both get marked.
+        assert_eq!(Feedback::score("erase", "speed").marks(), &[P, A, A, P, P]);
+    }
+
+    #[test]
+    fn feedback_pattern() {
+        let words = [
+            "hello", "lolly", "speed", "erase", "abide", "llama", "eerie",
+        ];
+
+        for guess in words {
+            for answer in words {
+                assert_eq!(
+                    pattern::<English>(guess, answer),
+                    Feedback::score(guess, answer).code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn feedback_partition() {
+        let buckets = partition("lolly", &["hello", "holly", "jolly", "dolly"]);
+
+        // `holly`, `jolly` and `dolly` all score [A, C, C, C, C].
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[&Feedback::score("lolly", "hello").code()], 1);
+        assert_eq!(buckets[&Feedback::score("lolly", "jolly").code()], 3);
+    }
+
+    #[test]
+    fn feedback_alphabet() {
+        // Accents on vowels are ignored in Spanish, but `ñ` is a letter of its own.
+        let feedback = Feedback::score_in::<Spanish, _, _>("árbol", "ARBOL");
+        assert!(feedback.is_solved());
+        assert_eq!(
+            Feedback::score_in::<Spanish, _, _>("señor", "senor").marks(),
+            &[C, C, A, C, C]
+        );
+        assert_eq!(Feedback::score("árbol", "arbol").marks(), &[A, C, C, C, C]);
+
+        for guess in ["árbol", "señor", "canto"] {
+            for answer in ["arbol", "señor", "cantó"] {
+                assert_eq!(
+                    pattern::<Spanish>(guess, answer),
+                    Feedback::score_in::<Spanish, _, _>(guess, answer).code()
+                );
+            }
+        }
+
+        let buckets = partition_in::<Spanish, _>("árbol", &["arbol", "árbol", "señor"]);
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[&Feedback::new(vec![C; 5]).code()], 2);
+    }
+
+    #[test]
+    fn feedback_display() {
+        assert_eq!(Feedback::score("lolly", "hello").to_string(), "BYGGB");
+    }
+
+    #[test]
+    fn feedback_code() {
+        assert_eq!(Feedback::new(vec![A, A, A]).code(), 0);
+        assert_eq!(Feedback::new(vec![A, P, C]).code(), 5);
+        assert_eq!(Feedback::new(vec![C, C, C]).code(), 26);
+        assert!(Feedback::new(vec![C, C, C]).is_solved());
+        assert!(!Feedback::new(vec![C, P, C]).is_solved());
+    }
+
+    #[test]
+    fn feedback_inferences() {
+        let inferences = Feedback::score("lolly", "hello").inferences("lolly");
+
+        assert!(inferences.contains(&Inference::new('l', 0, InferenceKind::AbsentLocal)));
+        assert!(inferences.contains(&Inference::new('l', 2, InferenceKind::Correct)));
+        assert!(inferences.contains(&Inference::new('l', 0, InferenceKind::Count(2))));
+        assert!(inferences.contains(&Inference::new('o', 0, InferenceKind::AtLeast(1))));
+        assert!(inferences.contains(&Inference::new('y', 0, InferenceKind::AbsentGlobal)));
+    }
+
+    #[test]
+    fn feedback_inferences_admit_answer() {
+        let words = [
+            "hello", "lolly", "speed", "erase", "abide", "llama", "eerie",
+        ];
+
+        for guess in words {
+            for answer in words {
+                let inferences = Feedback::score(guess, answer).inferences(guess);
+                assert!(
+                    inferences.iter().all(|inf| inf.filter(answer)),
+                    "{} rejected by its own feedback for {}",
+                    answer,
+                    guess
+                );
+            }
+        }
+    }
+}
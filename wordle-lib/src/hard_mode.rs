@@ -0,0 +1,124 @@
+use crate::alphabet::{Alphabet, English};
+use crate::{Inference, InferenceKind};
+
+/// A revealed hint that a guess fails to use.
+///
+/// In hard mode every correct letter must be kept in place and every present letter must be
+/// reused, although letters known to be absent may still be guessed.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub struct Violation<A: Alphabet = English> {
+    inference: Inference<A>,
+}
+
+impl<A: Alphabet> Violation<A> {
+    /// The inference that the guess breaks.
+    pub fn inference(&self) -> Inference<A> {
+        self.inference
+    }
+}
+
+impl<A: Alphabet> std::fmt::Display for Violation<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = self.inference.char().to_uppercase();
+        match self.inference.kind() {
+            InferenceKind::Correct => {
+                write!(f, "letter {} must be {}", self.inference.position() + 1, c)
+            }
+            InferenceKind::Count(n) | InferenceKind::AtLeast(n) if n > 1 => {
+                write!(f, "guess must contain at least {} {}s", n, c)
+            }
+            _ => write!(f, "guess must contain {}", c),
+        }
+    }
+}
+
+/// Checks whether `guess` uses every hint revealed by the inferences, as required in hard mode.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{hard_mode, Inference, InferenceKind};
+///
+/// let inferences = [
+///     Inference::new('r', 1, InferenceKind::Correct),
+///     Inference::new('e', 4, InferenceKind::Present),
+/// ];
+///
+/// assert!(hard_mode::check("greet", &inferences).is_ok());
+///
+/// let violation = hard_mode::check("trial", &inferences).unwrap_err();
+/// assert_eq!(violation.to_string(), "guess must contain E");
+/// ```
+pub fn check<A: Alphabet>(guess: &str, inf: &[Inference<A>]) -> Result<(), Violation<A>> {
+    match inf.iter().find(|i| !permits(i, guess)) {
+        Some(&inference) => Err(Violation { inference }),
+        None => Ok(()),
+    }
+}
+
+fn permits<A: Alphabet>(inference: &Inference<A>, guess: &str) -> bool {
+    let count = || {
+        guess
+            .chars()
+            .filter(|&q| A::normalize(q) == inference.char())
+            .count()
+    };
+
+    match inference.kind() {
+        InferenceKind::Correct => guess
+            .chars()
+            .nth(inference.position())
+            .map(|q| A::normalize(q) == inference.char())
+            .unwrap_or(false),
+        InferenceKind::Present => count() > 0,
+        InferenceKind::Count(n) | InferenceKind::AtLeast(n) => count() >= n,
+        InferenceKind::AbsentGlobal | InferenceKind::AbsentLocal => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check;
+    use crate::{Feedback, Inference, InferenceKind};
+
+    #[test]
+    fn hard_mode_correct() {
+        let inferences = [Inference::new('a', 2, InferenceKind::Correct)];
+
+        assert!(check("crane", &inferences).is_ok());
+        assert_eq!(
+            check("adieu", &inferences).unwrap_err().inference(),
+            inferences[0]
+        );
+        assert_eq!(
+            check("adieu", &inferences).unwrap_err().to_string(),
+            "letter 3 must be A"
+        );
+    }
+
+    #[test]
+    fn hard_mode_counts() {
+        let inferences = Feedback::score("lolly", "hello").inferences("lolly");
+
+        // Absent letters may be reused, but both `l`s and the `o` must be kept.
+        assert!(check("lolly", &inferences).is_ok());
+        assert!(check("hollo", &inferences).is_ok());
+        assert_eq!(
+            check("plots", &inferences).unwrap_err().to_string(),
+            "letter 3 must be L"
+        );
+        assert_eq!(
+            check("hulls", &inferences).unwrap_err().to_string(),
+            "guess must contain O"
+        );
+        assert_eq!(
+            check("jolts", &inferences).unwrap_err().to_string(),
+            "letter 4 must be L"
+        );
+    }
+}
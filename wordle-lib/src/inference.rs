@@ -1,4 +1,9 @@
+use crate::alphabet::{Alphabet, English};
+use crate::error::{check_word, WordleError};
+use std::marker::PhantomData;
+
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InferenceKind {
     /// The character does not appear in the word at all.
     AbsentGlobal,
@@ -14,45 +19,124 @@ pub enum InferenceKind {
     AtLeast(usize),
 }
 
+/// A fact about the answer, written in the letters of the alphabet `A`.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-pub struct Inference {
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "", from = "InferenceData")
+)]
+pub struct Inference<A: Alphabet = English> {
+    #[cfg_attr(feature = "serde", serde(rename = "char"))]
+    c: char,
+    position: usize,
+    kind: InferenceKind,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    alphabet: PhantomData<A>,
+}
+
+/// The fields of a deserialized [`Inference`], whose letter still has to be normalized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct InferenceData {
+    #[serde(rename = "char")]
     c: char,
     position: usize,
     kind: InferenceKind,
 }
 
+#[cfg(feature = "serde")]
+impl<A: Alphabet> From<InferenceData> for Inference<A> {
+    fn from(data: InferenceData) -> Self {
+        Self::new_in(data.c, data.position, data.kind)
+    }
+}
+
 impl Inference {
     pub fn new(c: char, position: usize, kind: InferenceKind) -> Self {
+        Self::new_in(c, position, kind)
+    }
+
+    /// Like [`Inference::new`], but checks that `c` is a letter and that `position` lies
+    /// within a word of length `len`.
+    pub fn try_new(
+        c: char,
+        position: usize,
+        kind: InferenceKind,
+        len: usize,
+    ) -> Result<Self, WordleError> {
+        Self::try_new_in(c, position, kind, len)
+    }
+}
+
+impl<A: Alphabet> Inference<A> {
+    /// Creates an inference about a word in the alphabet `A`, folding `c` into its letter.
+    pub fn new_in(c: char, position: usize, kind: InferenceKind) -> Self {
         Self {
-            c: c.to_ascii_lowercase(),
+            c: A::normalize(c),
             position,
             kind,
+            alphabet: PhantomData,
         }
     }
 
+    /// Like [`Inference::new_in`], but checks that `c` is a letter of `A` and that
+    /// `position` lies within a word of length `len`.
+    pub fn try_new_in(
+        c: char,
+        position: usize,
+        kind: InferenceKind,
+        len: usize,
+    ) -> Result<Self, WordleError> {
+        if A::fold(c).is_none() {
+            return Err(WordleError::InvalidLetter(c));
+        }
+        if position >= len {
+            return Err(WordleError::PositionOutOfRange { position, len });
+        }
+
+        Ok(Self::new_in(c, position, kind))
+    }
+
     pub fn char(&self) -> char {
         self.c
     }
 
-    pub fn filter(&self, w: &'static str) -> bool {
-        let c = w.chars().nth(self.position).unwrap().to_ascii_lowercase();
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn kind(&self) -> InferenceKind {
+        self.kind
+    }
+
+    /// Like [`Inference::filter`], but checks that `w` is made up of letters and is long
+    /// enough to have a letter at this inference's position.
+    pub fn try_filter(&self, w: &str) -> Result<bool, WordleError> {
+        let len = w.chars().count();
+        check_word::<A>(w, len)?;
+        if self.position >= len {
+            return Err(WordleError::PositionOutOfRange {
+                position: self.position,
+                len,
+            });
+        }
+
+        Ok(self.filter(w))
+    }
+
+    /// Checks whether `w` satisfies this inference.  Words too short to have a letter at
+    /// this inference's position are treated as having no letter there.
+    pub fn filter(&self, w: &str) -> bool {
+        let c = w.chars().nth(self.position).map(A::normalize);
+        let count = || w.chars().filter(|&q| A::normalize(q) == self.c).count();
         match self.kind {
-            InferenceKind::AbsentGlobal => !w.contains(self.c),
-            InferenceKind::AbsentLocal => self.c != c,
-            InferenceKind::Present => self.c != c && w.contains(self.c),
-            InferenceKind::Correct => self.c == c,
-            InferenceKind::Count(n) => {
-                w.chars()
-                    .filter(|&q| q.to_ascii_lowercase() == self.c)
-                    .count()
-                    == n
-            }
-            InferenceKind::AtLeast(n) => {
-                w.chars()
-                    .filter(|&q| q.to_ascii_lowercase() == self.c)
-                    .count()
-                    >= n
-            }
+            InferenceKind::AbsentGlobal => count() == 0,
+            InferenceKind::AbsentLocal => c != Some(self.c),
+            InferenceKind::Present => c != Some(self.c) && count() > 0,
+            InferenceKind::Correct => c == Some(self.c),
+            InferenceKind::Count(n) => count() == n,
+            InferenceKind::AtLeast(n) => count() >= n,
         }
     }
 }
@@ -60,14 +144,16 @@ impl Inference {
 #[cfg(test)]
 mod tests {
     use super::{Inference, InferenceKind};
+    use crate::alphabet::{English, Spanish};
+    use crate::WordleError;
 
     #[test]
     fn inference_filter_at_least() {
         let inference = Inference::new('u', 1, InferenceKind::AtLeast(2));
 
-        assert_eq!(inference.filter("TUUES"), true);
-        assert_eq!(inference.filter("trout"), false);
-        assert_eq!(inference.filter("tatou"), false);
+        assert!(inference.filter("TUUES"));
+        assert!(!inference.filter("trout"));
+        assert!(!inference.filter("tatou"));
     }
 
     #[test]
@@ -77,13 +163,13 @@ mod tests {
 
         // note that `cat` is not a match because InferenceKind::Present won't match
         // an `a` at the same position as the inference.
-        assert_eq!(inference.filter("cat"), false);
+        assert!(!inference.filter("cat"));
 
         // `tca` _is_ a match because it has an `a` not at position 1.
-        assert_eq!(inference.filter("tca"), true);
-        assert_eq!(inference.filter("dottttttg"), false);
-        assert_eq!(inference.filter("tttttttta"), true);
-        assert_eq!(inference.filter("aottttttttto"), true);
+        assert!(inference.filter("tca"));
+        assert!(!inference.filter("dottttttg"));
+        assert!(inference.filter("tttttttta"));
+        assert!(inference.filter("aottttttttto"));
     }
 
     #[test]
@@ -91,10 +177,10 @@ mod tests {
         // There should be an 'a' at a specific position in the word.
         let inference = Inference::new('a', 2, InferenceKind::Correct);
 
-        assert_eq!(inference.filter("ammmmmm"), false);
-        assert_eq!(inference.filter("zzzzzzz"), false);
-        assert_eq!(inference.filter("thamm"), true);
-        assert_eq!(inference.filter("ooaoa"), true);
+        assert!(!inference.filter("ammmmmm"));
+        assert!(!inference.filter("zzzzzzz"));
+        assert!(inference.filter("thamm"));
+        assert!(inference.filter("ooaoa"));
     }
 
     #[test]
@@ -103,11 +189,11 @@ mod tests {
         let inference = Inference::new('a', 1, InferenceKind::AbsentLocal);
 
         // contains an `a` but not at position 1.
-        assert_eq!(inference.filter("abbbbb"), true);
-        assert_eq!(inference.filter("ccccc"), true);
-        assert_eq!(inference.filter("tazzzz"), false);
-        assert_eq!(inference.filter("aaaaa"), false);
-        assert_eq!(inference.filter("pppa"), true);
+        assert!(inference.filter("abbbbb"));
+        assert!(inference.filter("ccccc"));
+        assert!(!inference.filter("tazzzz"));
+        assert!(!inference.filter("aaaaa"));
+        assert!(inference.filter("pppa"));
     }
 
     #[test]
@@ -115,10 +201,67 @@ mod tests {
         // There is no 'a' anywhere in the word.
         let inference = Inference::new('a', 0, InferenceKind::AbsentGlobal);
 
-        assert_eq!(inference.filter("bbbbbbb"), true);
-        assert_eq!(inference.filter("abbbbb"), false);
-        assert_eq!(inference.filter("bbbbba"), false);
-        assert_eq!(inference.filter("cdefghi"), true);
+        assert!(inference.filter("bbbbbbb"));
+        assert!(!inference.filter("abbbbb"));
+        assert!(!inference.filter("bbbbba"));
+        assert!(inference.filter("cdefghi"));
+    }
+
+    #[test]
+    fn inference_try_new() {
+        assert!(Inference::try_new('a', 4, InferenceKind::Correct, 5).is_ok());
+        assert_eq!(
+            Inference::try_new('a', 5, InferenceKind::Correct, 5),
+            Err(WordleError::PositionOutOfRange {
+                position: 5,
+                len: 5
+            })
+        );
+        assert_eq!(
+            Inference::try_new('-', 0, InferenceKind::Present, 5),
+            Err(WordleError::InvalidLetter('-'))
+        );
+    }
+
+    #[test]
+    fn inference_try_filter() {
+        let inference = Inference::new('a', 3, InferenceKind::AbsentLocal);
+
+        assert_eq!(inference.try_filter("bats"), Ok(true));
+        assert_eq!(
+            inference.try_filter("bat"),
+            Err(WordleError::PositionOutOfRange {
+                position: 3,
+                len: 3
+            })
+        );
+        assert_eq!(
+            inference.try_filter("it's"),
+            Err(WordleError::InvalidLetter('\''))
+        );
+
+        // The plain filter doesn't panic on short words.
+        assert!(inference.filter("bat"));
+    }
+
+    #[test]
+    fn inference_alphabet() {
+        let inference = Inference::<Spanish>::new_in('Ñ', 2, InferenceKind::Correct);
+        assert_eq!(inference.char(), 'ñ');
+        assert!(inference.filter("niño"));
+        assert!(inference.filter("NIÑO"));
+        assert!(!inference.filter("nino"));
+
+        // Accents on vowels are ignored.
+        let inference = Inference::<Spanish>::new_in('o', 1, InferenceKind::Count(1));
+        assert!(inference.filter("canción"));
+        assert!(inference.try_filter("canción").is_ok());
+
+        assert!(Inference::<English>::try_new_in('ñ', 0, InferenceKind::Present, 5).is_err());
+        assert_eq!(
+            Inference::<English>::new_in('a', 0, InferenceKind::Present).try_filter("niño"),
+            Err(WordleError::InvalidLetter('ñ'))
+        );
     }
 
     #[test]
@@ -126,11 +269,34 @@ mod tests {
         // There is exactly two `a`'s in the word.
         let inference = Inference::new('a', 2, InferenceKind::Count(2));
 
-        assert_eq!(inference.filter("bbbb"), false);
-        assert_eq!(inference.filter("abbb"), false);
-        assert_eq!(inference.filter("aabb"), true);
-        assert_eq!(inference.filter("bbaa"), true);
-        assert_eq!(inference.filter("aaab"), false);
-        assert_eq!(inference.filter("bbba"), false);
+        assert!(!inference.filter("bbbb"));
+        assert!(!inference.filter("abbb"));
+        assert!(inference.filter("aabb"));
+        assert!(inference.filter("bbaa"));
+        assert!(!inference.filter("aaab"));
+        assert!(!inference.filter("bbba"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn inference_serde() {
+        let inference = Inference::<Spanish>::new_in('ñ', 2, InferenceKind::AtLeast(2));
+        let json = serde_json::to_string(&inference).unwrap();
+        assert_eq!(json, r#"{"char":"ñ","position":2,"kind":{"AtLeast":2}}"#);
+        assert_eq!(
+            serde_json::from_str::<Inference<Spanish>>(&json).unwrap(),
+            inference
+        );
+
+        // Letters are normalized as by `new_in`.
+        let json = r#"{"char":"A","position":0,"kind":"Correct"}"#;
+        let inference = serde_json::from_str::<Inference>(json).unwrap();
+        assert_eq!(inference, Inference::new('a', 0, InferenceKind::Correct));
+        assert!(inference.filter("apple"));
+        let json = r#"{"char":"Ñ","position":2,"kind":{"AtLeast":2}}"#;
+        assert_eq!(
+            serde_json::from_str::<Inference<Spanish>>(json).unwrap(),
+            Inference::<Spanish>::new_in('ñ', 2, InferenceKind::AtLeast(2))
+        );
     }
 }
@@ -0,0 +1,429 @@
+use crate::alphabet::{Alphabet, English};
+use crate::{Inference, InferenceKind};
+use std::collections::{BTreeMap, BTreeSet};
+use std::marker::PhantomData;
+
+/// Two or more boards which can't all be right.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub struct Contradiction<A: Alphabet = English> {
+    inference: Inference<A>,
+    boards: Vec<usize>,
+}
+
+impl<A: Alphabet> Contradiction<A> {
+    /// The inference which couldn't be added.
+    pub fn inference(&self) -> Inference<A> {
+        self.inference
+    }
+
+    /// The boards involved, in ascending order.  This includes the board the inference came
+    /// from.
+    pub fn boards(&self) -> &[usize] {
+        &self.boards
+    }
+}
+
+impl<A: Alphabet> std::fmt::Display for Contradiction<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = self.inference.char().to_uppercase();
+        match self.boards.as_slice() {
+            [board] => write!(f, "board {} contradicts itself about {}", board + 1, c),
+            [rest @ .., last] => {
+                let rest: Vec<_> = rest.iter().map(|b| (b + 1).to_string()).collect();
+                write!(
+                    f,
+                    "boards {} and {} disagree about {}",
+                    rest.join(", "),
+                    last + 1,
+                    c
+                )
+            }
+            [] => write!(f, "contradiction about {}", c),
+        }
+    }
+}
+
+/// Everything known about the answer, merged from the inferences of any number of boards.
+///
+/// For each position this tracks the letter known to be there and the letters known not to be,
+/// and for each letter the fewest and most times it can appear.  Each fact remembers the board
+/// it came from, so that a contradiction can say which boards are to blame.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{Inference, InferenceKind, Knowledge};
+///
+/// let mut knowledge = Knowledge::new();
+/// knowledge.add(0, Inference::new('e', 0, InferenceKind::Count(1))).unwrap();
+/// knowledge.add(0, Inference::new('a', 1, InferenceKind::Correct)).unwrap();
+///
+/// assert!(knowledge.filter("bake"));
+/// assert!(!knowledge.filter("eave"));
+///
+/// let contradiction = knowledge
+///     .add(1, Inference::new('e', 0, InferenceKind::AtLeast(2)))
+///     .unwrap_err();
+/// assert_eq!(contradiction.boards(), [0, 1]);
+/// assert_eq!(contradiction.to_string(), "boards 1 and 2 disagree about E");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "", from = "KnowledgeData")
+)]
+pub struct Knowledge<A: Alphabet = English> {
+    /// The letter at each known position, and the board it came from.
+    correct: BTreeMap<usize, (char, usize)>,
+    /// The letters known not to be at each position, and the board each came from.
+    excluded: BTreeMap<usize, BTreeMap<char, usize>>,
+    /// The fewest times each letter appears, and the board it came from.
+    min: BTreeMap<char, (usize, usize)>,
+    /// The most times each letter appears, and the board it came from.
+    max: BTreeMap<char, (usize, usize)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    alphabet: PhantomData<A>,
+}
+
+/// The fields of a deserialized [`Knowledge`], whose letters still have to be normalized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct KnowledgeData {
+    correct: BTreeMap<usize, (char, usize)>,
+    excluded: BTreeMap<usize, BTreeMap<char, usize>>,
+    min: BTreeMap<char, (usize, usize)>,
+    max: BTreeMap<char, (usize, usize)>,
+}
+
+#[cfg(feature = "serde")]
+impl<A: Alphabet> From<KnowledgeData> for Knowledge<A> {
+    fn from(data: KnowledgeData) -> Self {
+        // Letters which only differ before normalizing are merged as `add` would merge them.
+        let mut knowledge = Self::new_in();
+        for (position, (c, board)) in data.correct {
+            knowledge.correct.insert(position, (A::normalize(c), board));
+        }
+        for (position, letters) in data.excluded {
+            for (c, board) in letters {
+                knowledge.exclude(board, A::normalize(c), position);
+            }
+        }
+        for (c, (n, board)) in data.min {
+            knowledge.raise_min(board, A::normalize(c), n);
+        }
+        for (c, (n, board)) in data.max {
+            knowledge.lower_max(board, A::normalize(c), n);
+        }
+        knowledge
+    }
+}
+
+impl Default for Knowledge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Knowledge {
+    pub fn new() -> Self {
+        Self::new_in()
+    }
+}
+
+impl<A: Alphabet> Knowledge<A> {
+    /// Creates an empty store of knowledge about a word in the alphabet `A`.
+    pub fn new_in() -> Self {
+        Self {
+            correct: BTreeMap::new(),
+            excluded: BTreeMap::new(),
+            min: BTreeMap::new(),
+            max: BTreeMap::new(),
+            alphabet: PhantomData,
+        }
+    }
+
+    /// Merges an inference from `board` into what is already known.
+    ///
+    /// If the inference contradicts what is known, nothing is changed.
+    pub fn add(&mut self, board: usize, inference: Inference<A>) -> Result<(), Contradiction<A>> {
+        let (c, position, kind) = (inference.char(), inference.position(), inference.kind());
+
+        if let Err(mut boards) = self.check(c, position, kind) {
+            boards.push(board);
+            boards.sort_unstable();
+            boards.dedup();
+            return Err(Contradiction { inference, boards });
+        }
+
+        match kind {
+            InferenceKind::Correct => {
+                self.correct.entry(position).or_insert((c, board));
+            }
+            InferenceKind::AbsentLocal => self.exclude(board, c, position),
+            InferenceKind::Present => {
+                self.exclude(board, c, position);
+                self.raise_min(board, c, 1);
+            }
+            InferenceKind::AbsentGlobal => self.lower_max(board, c, 0),
+            InferenceKind::Count(n) => {
+                self.raise_min(board, c, n);
+                self.lower_max(board, c, n);
+            }
+            InferenceKind::AtLeast(n) => self.raise_min(board, c, n),
+        }
+
+        Ok(())
+    }
+
+    /// Merges every inference from `board`, stopping at the first contradiction.
+    pub fn add_all<I: IntoIterator<Item = Inference<A>>>(
+        &mut self,
+        board: usize,
+        inferences: I,
+    ) -> Result<(), Contradiction<A>> {
+        inferences
+            .into_iter()
+            .try_for_each(|inference| self.add(board, inference))
+    }
+
+    /// The boards responsible for each fact `kind` would contradict.
+    fn check(&self, c: char, position: usize, kind: InferenceKind) -> Result<(), Vec<usize>> {
+        match kind {
+            InferenceKind::Correct => {
+                if let Some(&(d, board)) = self.correct.get(&position) {
+                    return if d == c { Ok(()) } else { Err(vec![board]) };
+                }
+                if let Some(&board) = self.excluded.get(&position).and_then(|e| e.get(&c)) {
+                    return Err(vec![board]);
+                }
+                let mut fixed = self.fixed(c);
+                match self.max.get(&c) {
+                    Some(&(max, board)) if fixed.len() >= max => {
+                        fixed.push(board);
+                        Err(fixed)
+                    }
+                    _ => Ok(()),
+                }
+            }
+            InferenceKind::AbsentLocal | InferenceKind::Present => {
+                match self.correct.get(&position) {
+                    Some(&(d, board)) if d == c => Err(vec![board]),
+                    _ if kind == InferenceKind::Present => self.check_counts(c, 1, None),
+                    _ => Ok(()),
+                }
+            }
+            InferenceKind::AbsentGlobal => self.check_counts(c, 0, Some(0)),
+            InferenceKind::Count(n) => self.check_counts(c, n, Some(n)),
+            InferenceKind::AtLeast(n) => self.check_counts(c, n, None),
+        }
+    }
+
+    fn check_counts(&self, c: char, min: usize, max: Option<usize>) -> Result<(), Vec<usize>> {
+        if let Some(&(known_max, board)) = self.max.get(&c) {
+            if min > known_max {
+                return Err(vec![board]);
+            }
+        }
+
+        if let Some(max) = max {
+            if let Some(&(known_min, board)) = self.min.get(&c) {
+                if known_min > max {
+                    return Err(vec![board]);
+                }
+            }
+            let fixed = self.fixed(c);
+            if fixed.len() > max {
+                return Err(fixed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The boards which placed `c` at each of its known positions.
+    fn fixed(&self, c: char) -> Vec<usize> {
+        self.correct
+            .values()
+            .filter(|&&(d, _)| d == c)
+            .map(|&(_, board)| board)
+            .collect()
+    }
+
+    fn exclude(&mut self, board: usize, c: char, position: usize) {
+        self.excluded
+            .entry(position)
+            .or_default()
+            .entry(c)
+            .or_insert(board);
+    }
+
+    fn raise_min(&mut self, board: usize, c: char, n: usize) {
+        let entry = self.min.entry(c).or_insert((0, board));
+        if n > entry.0 {
+            *entry = (n, board);
+        }
+    }
+
+    fn lower_max(&mut self, board: usize, c: char, n: usize) {
+        let entry = self.max.entry(c).or_insert((n, board));
+        if n < entry.0 {
+            *entry = (n, board);
+        }
+    }
+
+    /// The fewest inferences which say everything that is known.
+    pub fn inferences(&self) -> Vec<Inference<A>> {
+        let mut inferences: Vec<_> = self
+            .correct
+            .iter()
+            .map(|(&position, &(c, _))| Inference::new_in(c, position, InferenceKind::Correct))
+            .collect();
+
+        for (&position, letters) in &self.excluded {
+            if self.correct.contains_key(&position) {
+                continue;
+            }
+            inferences.extend(
+                letters
+                    .keys()
+                    .map(|&c| Inference::new_in(c, position, InferenceKind::AbsentLocal)),
+            );
+        }
+
+        let letters: BTreeSet<char> = self.min.keys().chain(self.max.keys()).copied().collect();
+        for c in letters {
+            let min = self.min.get(&c).map_or(0, |&(n, _)| n);
+            let kind = match self.max.get(&c) {
+                Some(&(0, _)) => InferenceKind::AbsentGlobal,
+                Some(&(max, _)) if max == min => InferenceKind::Count(max),
+                _ if min > 0 => InferenceKind::AtLeast(min),
+                _ => continue,
+            };
+            inferences.push(Inference::new_in(c, 0, kind));
+        }
+
+        inferences
+    }
+
+    /// Checks whether `word` could be the answer.
+    pub fn filter(&self, word: &str) -> bool {
+        let chars: Vec<char> = word.chars().map(A::normalize).collect();
+        let count = |c: char| chars.iter().filter(|&&d| d == c).count();
+
+        self.correct
+            .iter()
+            .all(|(&position, &(c, _))| chars.get(position) == Some(&c))
+            && self.excluded.iter().all(|(&position, letters)| {
+                chars.get(position).is_none_or(|c| !letters.contains_key(c))
+            })
+            && self.min.iter().all(|(&c, &(n, _))| count(c) >= n)
+            && self.max.iter().all(|(&c, &(n, _))| count(c) <= n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Knowledge;
+    use crate::{Feedback, Inference, InferenceKind};
+
+    #[test]
+    fn knowledge_merges_boards() {
+        let mut knowledge = Knowledge::new();
+        for (board, guess) in ["crane", "slope"].into_iter().enumerate() {
+            let inferences = Feedback::score(guess, "spite").inferences(guess);
+            knowledge.add_all(board, inferences).unwrap();
+        }
+
+        assert!(knowledge.filter("spite"));
+        assert!(knowledge.filter("spike"));
+        assert!(!knowledge.filter("slope"));
+        assert!(!knowledge.filter("smite"));
+
+        // The normalized inferences say the same thing.
+        let inferences = knowledge.inferences();
+        for word in ["spite", "spike", "slope", "smite", "sweet"] {
+            assert_eq!(
+                inferences.iter().all(|i| i.filter(word)),
+                knowledge.filter(word)
+            );
+        }
+    }
+
+    #[test]
+    fn knowledge_contradictions() {
+        let mut knowledge = Knowledge::new();
+        knowledge
+            .add(0, Inference::new('a', 1, InferenceKind::Correct))
+            .unwrap();
+        knowledge
+            .add(1, Inference::new('b', 2, InferenceKind::Correct))
+            .unwrap();
+
+        // A different letter at a known position.
+        let contradiction = knowledge
+            .add(2, Inference::new('c', 1, InferenceKind::Correct))
+            .unwrap_err();
+        assert_eq!(contradiction.boards(), [0, 2]);
+
+        // A letter that can't be absent.
+        let contradiction = knowledge
+            .add(3, Inference::new('b', 0, InferenceKind::AbsentGlobal))
+            .unwrap_err();
+        assert_eq!(contradiction.boards(), [1, 3]);
+
+        // Nothing was changed by the failures.
+        assert!(knowledge.filter("xab"));
+
+        knowledge
+            .add(2, Inference::new('a', 0, InferenceKind::Count(1)))
+            .unwrap();
+        let contradiction = knowledge
+            .add(3, Inference::new('a', 0, InferenceKind::Correct))
+            .unwrap_err();
+        assert_eq!(contradiction.boards(), [0, 2, 3]);
+        assert_eq!(
+            contradiction.to_string(),
+            "boards 1, 3 and 4 disagree about A"
+        );
+    }
+
+    #[test]
+    fn knowledge_repeated_inferences() {
+        let mut knowledge = Knowledge::new();
+        let inferences = Feedback::score("eerie", "there").inferences("eerie");
+
+        knowledge.add_all(0, inferences.clone()).unwrap();
+        knowledge.add_all(1, inferences).unwrap();
+
+        assert!(knowledge.filter("there"));
+        assert!(!knowledge.filter("three"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn knowledge_serde() {
+        let mut knowledge = Knowledge::new();
+        let inferences = Feedback::score("eerie", "there").inferences("eerie");
+        knowledge.add_all(0, inferences).unwrap();
+
+        let json = serde_json::to_string(&knowledge).unwrap();
+        let round_trip: Knowledge = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip, knowledge);
+        assert_eq!(round_trip.inferences(), knowledge.inferences());
+
+        // Letters are normalized as by `Inference::new_in`.
+        let upper = ["e", "h", "i", "r", "t"].iter().fold(json, |json, c| {
+            json.replace(&format!("\"{}\"", c), &format!("\"{}\"", c.to_uppercase()))
+        });
+        assert!(upper.contains("\"E\""));
+        let upper: Knowledge = serde_json::from_str(&upper).unwrap();
+        assert_eq!(upper, knowledge);
+        assert!(upper.filter("there"));
+    }
+}
@@ -1,7 +1,34 @@
+pub mod adversary;
+pub mod alphabet;
 pub mod engine;
+pub mod entropy;
+pub mod error;
+pub mod explain;
+pub mod feedback;
+pub mod hard_mode;
 pub mod inference;
+pub mod knowledge;
+pub mod minimax;
+pub mod multi;
 pub mod overlap;
+mod par;
+pub mod patterns;
+pub mod scorer;
+pub mod session;
+pub mod simulate;
+pub mod tree;
 
-pub use engine::Engine;
+pub use alphabet::Alphabet;
+pub use engine::{DynEngine, Engine};
+pub use entropy::entropy;
+pub use error::WordleError;
+pub use feedback::{Feedback, Mark};
 pub use inference::{Inference, InferenceKind};
+pub use knowledge::{Contradiction, Knowledge};
+pub use minimax::worst_case;
+pub use multi::MultiSession;
 pub use overlap::Overlap;
+pub use patterns::PatternTable;
+pub use scorer::Scorer;
+pub use session::Session;
+pub use tree::DecisionTree;
@@ -0,0 +1,56 @@
+use crate::alphabet::{Alphabet, English};
+use crate::feedback::partition_in;
+
+/// Computes the number of candidates that could remain, in the worst case, after guessing
+/// `guess` when the answer is any of the `candidates`.
+///
+/// This is the size of the largest bucket when the candidates are partitioned by the feedback
+/// `guess` would receive against each of them.  Ranking guesses by the smallest worst case is
+/// Knuth's strategy for Mastermind.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::worst_case;
+///
+/// // `cat` can't tell `cot` and `cut` apart, while `dig` can't tell any of them apart.
+/// let words = ["cat", "cot", "cut", "hat"];
+///
+/// assert_eq!(worst_case("cat", &words), 2);
+/// assert_eq!(worst_case("dig", &words), 4);
+/// ```
+pub fn worst_case<S: AsRef<str>>(guess: &str, candidates: &[S]) -> usize {
+    worst_case_in::<English, _>(guess, candidates)
+}
+
+/// Like [`worst_case`], for words in the alphabet `A`.
+pub fn worst_case_in<A: Alphabet, S: AsRef<str>>(guess: &str, candidates: &[S]) -> usize {
+    partition_in::<A, _>(guess, candidates)
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::worst_case;
+
+    #[test]
+    fn worst_case_empty() {
+        let words: [&str; 0] = [];
+        assert_eq!(worst_case("cat", &words), 0);
+    }
+
+    #[test]
+    fn worst_case_buckets() {
+        // `bat` scores [A, C, C] against both `cat` and `hat`.
+        let words = ["bat", "cat", "hat", "dot"];
+        assert_eq!(worst_case("bat", &words), 2);
+
+        // `dot` scores [A, A, C] against `bat`, `cat` and `hat`.
+        assert_eq!(worst_case("dot", &words), 3);
+
+        // `chb` tells all four apart.
+        assert_eq!(worst_case("chb", &words), 1);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::alphabet::{Alphabet, English};
+use crate::{Contradiction, DynEngine, Feedback, Mark, Session};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// A game in which every guess is scored against several answers at once, as in Dordle,
+/// Quordle and Octordle.
+///
+/// Each board keeps its own [`Session`].  Once a board has been solved it ignores any further
+/// feedback, and no longer counts towards the ranking of guesses.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{DynEngine, Feedback, MultiSession};
+///
+/// let engine = DynEngine::new(3, ["bat", "cat", "hat", "dot", "dog"]);
+/// let mut game = MultiSession::new(engine, 2);
+///
+/// let answers = ["hat", "dog"];
+/// let feedback: Vec<_> = answers.iter().map(|a| Feedback::score("dog", a)).collect();
+/// game.apply("dog", &feedback).unwrap();
+///
+/// assert_eq!(game.unsolved().collect::<Vec<_>>(), [0]);
+/// assert_eq!(game.board(0).candidates(), ["bat", "cat", "hat"]);
+/// ```
+pub struct MultiSession<'w, A: Alphabet = English> {
+    engine: Arc<DynEngine<'w, A>>,
+    boards: Vec<Session<'w, A>>,
+    solved: Vec<bool>,
+}
+
+impl<'w, A: Alphabet> Clone for MultiSession<'w, A> {
+    fn clone(&self) -> Self {
+        Self {
+            engine: Arc::clone(&self.engine),
+            boards: self.boards.clone(),
+            solved: self.solved.clone(),
+        }
+    }
+}
+
+impl<'w, A: Alphabet> MultiSession<'w, A> {
+    /// Starts a game with `boards` answers, each of which could be any word known to `engine`.
+    pub fn new<E: Into<Arc<DynEngine<'w, A>>>>(engine: E, boards: usize) -> Self {
+        let engine = engine.into();
+        Self {
+            boards: (0..boards)
+                .map(|_| Session::new(Arc::clone(&engine)))
+                .collect(),
+            solved: vec![false; boards],
+            engine,
+        }
+    }
+
+    pub fn engine(&self) -> &DynEngine<'w, A> {
+        &self.engine
+    }
+
+    /// The number of boards.
+    pub fn len(&self) -> usize {
+        self.boards.len()
+    }
+
+    /// Returns `true` if there are no boards.
+    pub fn is_empty(&self) -> bool {
+        self.boards.is_empty()
+    }
+
+    /// The session for the given board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `board` is out of range.
+    pub fn board(&self, board: usize) -> &Session<'w, A> {
+        &self.boards[board]
+    }
+
+    /// Returns `true` if the given board has been solved.
+    pub fn is_solved(&self, board: usize) -> bool {
+        self.solved[board]
+    }
+
+    /// The boards which haven't been solved yet.
+    pub fn unsolved(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(move |&board| !self.solved[board])
+    }
+
+    /// Returns `true` once every board has been solved.
+    pub fn is_finished(&self) -> bool {
+        self.solved.iter().all(|&solved| solved)
+    }
+
+    /// Plays `guess`, which received `feedback[i]` on board `i`.  Feedback for boards which
+    /// have already been solved is ignored.
+    ///
+    /// If the feedback contradicts an earlier turn on any board, the game is left unchanged and
+    /// the board is returned along with the contradiction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't one feedback per board, or if `guess` and a feedback have
+    /// different lengths.
+    pub fn apply(
+        &mut self,
+        guess: &str,
+        feedback: &[Feedback],
+    ) -> Result<(), (usize, Contradiction<A>)> {
+        assert_eq!(
+            feedback.len(),
+            self.len(),
+            "expected feedback for every board"
+        );
+
+        let mut boards = self.boards.clone();
+        let mut solved = self.solved.clone();
+        for board in self.unsolved() {
+            boards[board]
+                .apply(guess, &feedback[board])
+                .map_err(|contradiction| (board, contradiction))?;
+            solved[board] = feedback[board].marks().iter().all(|&m| m == Mark::Correct);
+        }
+
+        self.boards = boards;
+        self.solved = solved;
+        Ok(())
+    }
+
+    /// The words which could still be the answer to any unsolved board, in order.
+    pub fn candidates(&self) -> Vec<&'w str> {
+        let candidates: BTreeSet<_> = self
+            .unsolved()
+            .flat_map(|board| self.boards[board].candidates().iter().copied())
+            .collect();
+        candidates.into_iter().collect()
+    }
+
+    /// Ranks the words which could still be the answer to any unsolved board, by their combined
+    /// score across the unsolved boards.  In hard mode, only candidates which use every hint
+    /// revealed on every unsolved board are ranked.
+    pub fn rank(&self) -> Vec<(&'w str, f64)> {
+        self.rank_guesses(self.legal(self.candidates()))
+    }
+
+    /// Ranks every word known to the engine by its combined score across the unsolved boards,
+    /// whether or not it could be an answer.  In hard mode, only guesses which use every hint
+    /// revealed on every unsolved board are ranked.
+    pub fn rank_probes(&self) -> Vec<(&'w str, f64)> {
+        self.rank_guesses(self.legal(self.engine.guesses(&[])))
+    }
+
+    /// Keeps the guesses which are legal on every unsolved board.
+    fn legal(&self, mut guesses: Vec<&'w str>) -> Vec<&'w str> {
+        for board in self.unsolved() {
+            let inferences = self.boards[board].knowledge().inferences();
+            guesses.retain(|guess| self.engine.check_guess(guess, &inferences).is_ok());
+        }
+        guesses
+    }
+
+    fn rank_guesses(&self, guesses: Vec<&'w str>) -> Vec<(&'w str, f64)> {
+        let mut scores = vec![0.0; guesses.len()];
+        for board in self.unsolved() {
+            let board = self
+                .engine
+                .score_guesses(&guesses, self.boards[board].candidates());
+            for (score, s) in scores.iter_mut().zip(board) {
+                *score += s;
+            }
+        }
+
+        self.engine
+            .sort_ranked(guesses.iter().copied().zip(scores).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiSession;
+    use crate::{DynEngine, Feedback};
+
+    const WORDS: [&str; 6] = ["bat", "cat", "hat", "mat", "dot", "dog"];
+
+    fn play(game: &mut MultiSession, guess: &str, answers: &[&str]) {
+        let feedback: Vec<_> = answers.iter().map(|a| Feedback::score(guess, a)).collect();
+        game.apply(guess, &feedback).unwrap();
+    }
+
+    #[test]
+    fn multi_boards_are_independent() {
+        let mut game = MultiSession::new(DynEngine::new(3, WORDS), 3);
+        let answers = ["mat", "dot", "cat"];
+
+        play(&mut game, "dog", &answers);
+        assert_eq!(game.board(0).candidates(), ["bat", "cat", "hat", "mat"]);
+        assert_eq!(game.board(1).candidates(), ["dot"]);
+        assert_eq!(game.board(2).candidates(), ["bat", "cat", "hat", "mat"]);
+
+        // Solved boards drop out, and ignore later feedback.
+        play(&mut game, "dot", &answers);
+        assert!(game.is_solved(1));
+        assert_eq!(game.unsolved().collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(game.candidates(), ["bat", "cat", "hat", "mat"]);
+
+        play(&mut game, "cat", &answers);
+        assert_eq!(game.board(1).len(), 2);
+        assert_eq!(game.board(0).candidates(), ["bat", "hat", "mat"]);
+        assert!(game.is_solved(2));
+        assert!(!game.is_finished());
+
+        play(&mut game, "mat", &answers);
+        assert!(game.is_finished());
+        assert!(game.rank().is_empty());
+    }
+
+    #[test]
+    fn multi_rank_combines_boards() {
+        let engine = DynEngine::new(3, WORDS);
+        let mut game = MultiSession::new(DynEngine::new(3, WORDS), 2);
+        play(&mut game, "dog", &["hat", "dot"]);
+
+        let expected: Vec<_> = engine
+            .rank_probes(&[])
+            .into_iter()
+            .map(|(w, _)| {
+                let score = [0, 1]
+                    .iter()
+                    .map(|&b| {
+                        let candidates = game.board(b).candidates();
+                        engine.score_guesses(&[w], candidates)[0]
+                    })
+                    .sum::<f64>();
+                (w, score)
+            })
+            .collect();
+        let mut expected = engine.sort_ranked(expected);
+        expected.retain(|(w, _)| game.candidates().contains(w));
+
+        assert_eq!(game.rank(), expected);
+        assert_eq!(game.candidates(), ["bat", "cat", "dot", "hat", "mat"]);
+    }
+
+    #[test]
+    fn multi_no_boards() {
+        let game = MultiSession::new(DynEngine::new(3, WORDS), 0);
+
+        assert!(game.is_empty());
+        assert!(game.is_finished());
+        assert!(game.rank().is_empty());
+        assert_eq!(game.rank_probes().len(), WORDS.len());
+    }
+
+    #[test]
+    fn multi_hard_mode() {
+        let mut game = MultiSession::new(DynEngine::new(3, WORDS).hard_mode(true), 2);
+        play(&mut game, "dog", &["hat", "dot"]);
+
+        // Board 1 revealed `d` and `o`, which every probe must now use.
+        let probes: Vec<_> = game.rank_probes().into_iter().map(|(w, _)| w).collect();
+        assert_eq!(probes.len(), 2);
+        assert!(probes.iter().all(|w| w.starts_with("do")));
+
+        // The same goes for the candidates, so none of board 0's are ranked.
+        let ranked: Vec<_> = game.rank().into_iter().map(|(w, _)| w).collect();
+        assert_eq!(ranked, ["dot"]);
+
+        // Once board 1 is solved, only the `t` revealed on board 0 has to be used.
+        play(&mut game, "dot", &["hat", "dot"]);
+        let probes: Vec<_> = game.rank_probes().into_iter().map(|(w, _)| w).collect();
+        assert_eq!(probes.len(), 5);
+        assert!(probes.iter().all(|w| w.ends_with('t')));
+    }
+
+    #[test]
+    fn multi_contradiction() {
+        let mut game = MultiSession::new(DynEngine::new(3, WORDS), 2);
+        play(&mut game, "dog", &["hat", "dot"]);
+
+        // Board 1 already knows its answer has no `g`.
+        let feedback = [Feedback::score("dog", "hat"), Feedback::score("dog", "dog")];
+        let (board, _) = game.apply("dog", &feedback).unwrap_err();
+        assert_eq!(board, 1);
+
+        // Neither board took the turn.
+        assert_eq!(game.board(0).len(), 1);
+        assert_eq!(game.board(1).len(), 1);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::alphabet::{Alphabet, English};
 use std::collections::HashSet;
 
 /// Represents the total overlap between a word (called the `source`) and all other words (`targets`).
@@ -34,6 +35,7 @@ use std::collections::HashSet;
 /// ```
 ///
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Overlap {
     pub total: usize,
     pub partial: usize,
@@ -64,16 +66,18 @@ impl Overlap {
         }
     }
 
-    fn _from_words(l: &str, r: &str) -> Self {
+    fn _from_words<A: Alphabet>(l: &str, r: &str) -> Self {
+        let l: Vec<char> = l.chars().map(A::normalize).collect();
+        let r: Vec<char> = r.chars().map(A::normalize).collect();
         assert_eq!(l.len(), r.len());
         let mut overlap = Overlap::new(0, 0);
         let mut seen = HashSet::with_capacity(l.len());
 
-        for (cl, cr) in l.chars().zip(r.chars()) {
+        for (&cl, &cr) in l.iter().zip(r.iter()) {
             if cl == cr {
                 overlap.total += 1;
             }
-            if l.contains(cr) && seen.insert(cr) {
+            if l.contains(&cr) && seen.insert(cr) {
                 overlap.partial += 1;
             }
         }
@@ -82,13 +86,101 @@ impl Overlap {
     }
 
     pub fn from_words<L: AsRef<str>, R: AsRef<str>>(l: L, r: R) -> Self {
-        Self::_from_words(l.as_ref(), r.as_ref())
+        Self::from_words_in::<English, _, _>(l, r)
+    }
+
+    /// Like [`Overlap::from_words`], for words in the alphabet `A`.
+    pub fn from_words_in<A: Alphabet, L: AsRef<str>, R: AsRef<str>>(l: L, r: R) -> Self {
+        Self::_from_words::<A>(l.as_ref(), r.as_ref())
+    }
+
+    /// Combines both components into a single score, counting a positional match as three
+    /// times as valuable as a match anywhere in the word.
+    pub fn weighted(&self) -> usize {
+        self.total + self.partial / 3
     }
 }
 
+/// Determines the overlap between each guess and all of the candidates, which are written in
+/// the alphabet `A`.
+pub(crate) fn overlaps<A: Alphabet, G: AsRef<str>, C: AsRef<str>>(
+    guesses: &[G],
+    candidates: &[C],
+) -> Vec<Overlap> {
+    overlaps_with(A::index, A::LETTERS.len(), guesses, candidates)
+}
+
+/// Like [`overlaps`], for an alphabet of `letters` letters given by its `index` function.
+pub(crate) fn overlaps_with<G: AsRef<str>, C: AsRef<str>>(
+    index: fn(char) -> Option<usize>,
+    letters: usize,
+    guesses: &[G],
+    candidates: &[C],
+) -> Vec<Overlap> {
+    // We collect everything into two buckets: one based on "has this character"
+    // and one based on "has this character at this position".
+    let mut character_buckets = vec![0; letters];
+    let mut positional_buckets: Vec<Vec<usize>> = Vec::new();
+
+    for word in candidates {
+        let mut seen = vec![false; letters];
+        for (j, c) in letter_iter(index, word.as_ref()) {
+            if !seen[c] {
+                character_buckets[c] += 1;
+                seen[c] = true;
+            }
+            if positional_buckets.len() <= j {
+                positional_buckets.resize(j + 1, vec![0; letters]);
+            }
+            positional_buckets[j][c] += 1;
+        }
+    }
+
+    guesses
+        .iter()
+        .map(|w| {
+            let mut overlap = Overlap::default();
+            let mut seen = vec![false; letters];
+
+            for (i, c) in letter_iter(index, w.as_ref()) {
+                if !seen[c] {
+                    overlap.partial += character_buckets[c];
+                    seen[c] = true;
+                }
+                overlap.total += positional_buckets.get(i).map_or(0, |b| b[c]);
+            }
+
+            overlap
+        })
+        .collect()
+}
+
+/// The position and alphabet index of each letter in `w`, skipping anything else.
+fn letter_iter(
+    index: fn(char) -> Option<usize>,
+    w: &str,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    w.chars()
+        .enumerate()
+        .filter_map(move |(i, c)| index(c).map(|c| (i, c)))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Overlap;
+    use super::{overlaps, Overlap};
+    use crate::alphabet::Spanish;
+
+    #[test]
+    fn overlap_alphabet() {
+        assert_eq!(
+            Overlap::from_words_in::<Spanish, _, _>("niño", "NIÑA"),
+            Overlap::new(3, 3)
+        );
+        assert_eq!(
+            overlaps::<Spanish, _, _>(&["niño"], &["niña", "caña"]),
+            [Overlap::new(4, 4)]
+        );
+    }
 
     #[test]
     fn overlap_sum() {
@@ -97,4 +189,12 @@ mod tests {
         let sum = overlaps.into_iter().sum::<Overlap>();
         assert_eq!(sum, Overlap::new(17, 11));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn overlap_serde() {
+        let overlap = Overlap::new(4, 2);
+        let json = serde_json::to_string(&overlap).unwrap();
+        assert_eq!(serde_json::from_str::<Overlap>(&json).unwrap(), overlap);
+    }
 }
@@ -0,0 +1,90 @@
+//! Helpers which run on multiple threads when the `parallel` feature is enabled, and on the
+//! current thread otherwise.  Either way, results are returned in the same order as the input.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Applies `f` to every item.
+#[cfg(feature = "parallel")]
+pub(crate) fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    items.par_iter().map(f).collect()
+}
+
+/// Applies `f` to every item.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    F: Fn(&T) -> R,
+{
+    items.iter().map(f).collect()
+}
+
+/// Keeps the items for which `f` returns `true`.
+#[cfg(feature = "parallel")]
+pub(crate) fn filter<T, F>(items: &[T], f: F) -> Vec<T>
+where
+    T: Copy + Sync + Send,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    items.par_iter().filter(|t| f(t)).copied().collect()
+}
+
+/// Keeps the items for which `f` returns `true`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn filter<T, F>(items: &[T], f: F) -> Vec<T>
+where
+    T: Copy,
+    F: Fn(&T) -> bool,
+{
+    items.iter().filter(|t| f(t)).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter, map};
+    use crate::scorer::{Candidates, EntropyScorer, Scorer};
+    use crate::{Engine, Feedback};
+
+    #[test]
+    fn par_preserves_order() {
+        let items: Vec<usize> = (0..10_000).collect();
+
+        assert_eq!(
+            map(&items, |i| i * 2),
+            (0..10_000).map(|i| i * 2).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            filter(&items, |i| i % 3 == 0),
+            (0..10_000).filter(|i| i % 3 == 0).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn par_matches_serial_engine() {
+        let engine = Engine::<5>::new(wordle_dict::words_of_len(5))
+            .answers(wordle_dict::ANSWERS.iter().copied())
+            .scorer(EntropyScorer);
+        let inferences = Feedback::score("crane", "plate").inferences("crane");
+
+        // Score every guess one at a time on this thread, which is what `parallel` must match.
+        let candidates = engine.candidates(&inferences);
+        let serial = |guesses: &[&'static str]| {
+            let scores = guesses
+                .iter()
+                .map(|&g| (g, EntropyScorer.score(g, &Candidates::new(&candidates))))
+                .collect();
+            engine.sort_ranked(scores)
+        };
+
+        assert_eq!(engine.rank(&inferences), serial(&candidates));
+        assert_eq!(
+            engine.rank_probes(&inferences),
+            serial(wordle_dict::words_of_len(5))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use crate::alphabet::{Alphabet, English};
+use crate::feedback::pattern;
+use crate::par;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::sync::Arc;
+
+const MAGIC: &[u8; 4] = b"WPT1";
+
+/// The longest word whose feedback fits in a single byte, since `3^5 = 243`.
+pub const MAX_LEN: usize = 5;
+
+/// A precomputed table of the feedback (see [`Feedback::code`](crate::Feedback::code)) every
+/// guess receives against every answer, stored as one byte per pair.
+///
+/// # On-disk format
+///
+/// [`PatternTable::write_to`] produces the four bytes `WPT1`, the number of guesses and the
+/// number of answers as little-endian `u32`s, each guess and then each answer terminated by a
+/// newline, and finally the table itself in guess-major order.  Since the table makes up almost
+/// all of the file, [`PatternTable::from_shared`] can use a memory-mapped file in place, without
+/// copying it.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{Feedback, PatternTable};
+///
+/// let words = ["bat", "cat", "hat"];
+/// let table = PatternTable::new(&words, &words);
+///
+/// let code = Feedback::score("bat", "cat").code();
+/// assert_eq!(table.get("bat", "cat"), Some(code as u8));
+///
+/// let mut bytes = Vec::new();
+/// table.write_to(&mut bytes).unwrap();
+/// assert_eq!(PatternTable::from_shared(bytes).unwrap(), table);
+/// ```
+#[derive(Clone)]
+pub struct PatternTable {
+    guesses: Vec<String>,
+    answers: Vec<String>,
+    guess_index: HashMap<String, usize>,
+    answer_index: HashMap<String, usize>,
+    bytes: Arc<dyn AsRef<[u8]> + Send + Sync>,
+    offset: usize,
+}
+
+impl PatternTable {
+    /// Computes the feedback of every guess against every answer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any word is longer than [`MAX_LEN`] characters.
+    pub fn new<G: AsRef<str>, W: AsRef<str>>(guesses: &[G], answers: &[W]) -> Self {
+        Self::new_in::<English, _, _>(guesses, answers)
+    }
+
+    /// Like [`PatternTable::new`], for words in the alphabet `A`.  The table should only be
+    /// given to engines for the same alphabet.
+    pub fn new_in<A: Alphabet, G: AsRef<str>, W: AsRef<str>>(guesses: &[G], answers: &[W]) -> Self {
+        let guesses: Vec<String> = guesses.iter().map(|w| w.as_ref().to_owned()).collect();
+        let answers: Vec<String> = answers.iter().map(|w| w.as_ref().to_owned()).collect();
+        assert!(guesses
+            .iter()
+            .chain(answers.iter())
+            .all(|w| w.chars().count() <= MAX_LEN));
+
+        let rows = par::map(&guesses, |guess| {
+            answers
+                .iter()
+                .map(|answer| pattern::<A>(guess, answer) as u8)
+                .collect::<Vec<_>>()
+        });
+        let table = rows.concat();
+
+        Self::from_parts(guesses, answers, Arc::new(table), 0)
+    }
+
+    fn from_parts(
+        guesses: Vec<String>,
+        answers: Vec<String>,
+        bytes: Arc<dyn AsRef<[u8]> + Send + Sync>,
+        offset: usize,
+    ) -> Self {
+        let index = |words: &[String]| {
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| (w.clone(), i))
+                .collect()
+        };
+
+        Self {
+            guess_index: index(&guesses),
+            answer_index: index(&answers),
+            guesses,
+            answers,
+            bytes,
+            offset,
+        }
+    }
+
+    /// Loads a table in the on-disk format from `bytes`, which may be any owned buffer or
+    /// memory map.  The table is used in place.
+    pub fn from_shared<B: AsRef<[u8]> + Send + Sync + 'static>(bytes: B) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_owned());
+
+        let data = bytes.as_ref();
+        if data.len() < 12 || &data[..4] != MAGIC {
+            return Err(invalid("not a pattern table"));
+        }
+        let guess_count = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+        let answer_count = u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize;
+
+        // Every word takes at least its newline, so counts the data can't hold are rejected
+        // before anything is allocated for them.
+        let word_count = guess_count.checked_add(answer_count);
+        if !matches!(word_count, Some(count) if count <= data.len() - 12) {
+            return Err(invalid("truncated word list"));
+        }
+        let table_len = guess_count
+            .checked_mul(answer_count)
+            .ok_or_else(|| invalid("table has the wrong size"))?;
+
+        let mut offset = 12;
+        let mut read_words = |count: usize| -> io::Result<Vec<String>> {
+            let mut words = Vec::new();
+            for _ in 0..count {
+                let len = data[offset..]
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .ok_or_else(|| invalid("truncated word list"))?;
+                let word = std::str::from_utf8(&data[offset..offset + len])
+                    .map_err(|_| invalid("word is not valid UTF-8"))?;
+                words.push(word.to_owned());
+                offset += len + 1;
+            }
+            Ok(words)
+        };
+        let guesses = read_words(guess_count)?;
+        let answers = read_words(answer_count)?;
+
+        if data.len() - offset != table_len {
+            return Err(invalid("table has the wrong size"));
+        }
+
+        Ok(Self::from_parts(guesses, answers, Arc::new(bytes), offset))
+    }
+
+    /// Reads a table in the on-disk format.
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_shared(bytes)
+    }
+
+    /// Writes the table in the on-disk format.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(self.guesses.len() as u32).to_le_bytes())?;
+        writer.write_all(&(self.answers.len() as u32).to_le_bytes())?;
+        for word in self.guesses.iter().chain(self.answers.iter()) {
+            writer.write_all(word.as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+        writer.write_all(self.table())
+    }
+
+    fn table(&self) -> &[u8] {
+        &(*self.bytes).as_ref()[self.offset..]
+    }
+
+    pub fn guesses(&self) -> &[String] {
+        &self.guesses
+    }
+
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    pub fn guess_index(&self, guess: &str) -> Option<usize> {
+        self.guess_index.get(guess).copied()
+    }
+
+    pub fn answer_index(&self, answer: &str) -> Option<usize> {
+        self.answer_index.get(answer).copied()
+    }
+
+    /// The feedback code for the guess and answer at the given indices.
+    pub fn code(&self, guess: usize, answer: usize) -> u8 {
+        self.table()[guess * self.answers.len() + answer]
+    }
+
+    /// The feedback code for `guess` against `answer`, if both are in the table.
+    pub fn get(&self, guess: &str, answer: &str) -> Option<u8> {
+        Some(self.code(self.guess_index(guess)?, self.answer_index(answer)?))
+    }
+}
+
+impl PartialEq for PatternTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.guesses == other.guesses
+            && self.answers == other.answers
+            && self.table() == other.table()
+    }
+}
+
+impl std::fmt::Debug for PatternTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PatternTable")
+            .field("guesses", &self.guesses.len())
+            .field("answers", &self.answers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatternTable;
+    use crate::Feedback;
+
+    const GUESSES: [&str; 4] = ["bat", "cat", "chb", "dot"];
+    const ANSWERS: [&str; 3] = ["bat", "hat", "dot"];
+
+    #[test]
+    fn pattern_table_codes() {
+        let table = PatternTable::new(&GUESSES, &ANSWERS);
+
+        for (i, guess) in GUESSES.iter().enumerate() {
+            for (j, answer) in ANSWERS.iter().enumerate() {
+                let code = Feedback::score(guess, answer).code() as u8;
+                assert_eq!(table.code(i, j), code);
+                assert_eq!(table.get(guess, answer), Some(code));
+            }
+        }
+
+        assert_eq!(table.get("hat", "bat"), None);
+        assert_eq!(table.get("bat", "cat"), None);
+    }
+
+    #[test]
+    fn pattern_table_round_trip() {
+        let table = PatternTable::new(&GUESSES, &ANSWERS);
+
+        let mut bytes = Vec::new();
+        table.write_to(&mut bytes).unwrap();
+
+        // Header, then "bat\ncat\nchb\ndot\n", "bat\nhat\ndot\n" and 12 codes.
+        assert_eq!(bytes.len(), 12 + 16 + 12 + 12);
+
+        let loaded = PatternTable::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(loaded, table);
+        assert_eq!(loaded.guesses(), GUESSES);
+        assert_eq!(loaded.answer_index("dot"), Some(2));
+    }
+
+    #[test]
+    fn pattern_table_invalid() {
+        let table = PatternTable::new(&GUESSES, &ANSWERS);
+        let mut bytes = Vec::new();
+        table.write_to(&mut bytes).unwrap();
+
+        assert!(PatternTable::from_shared(b"nope".to_vec()).is_err());
+        assert!(PatternTable::from_shared(bytes[..bytes.len() - 1].to_vec()).is_err());
+        assert!(PatternTable::from_shared(bytes[..20].to_vec()).is_err());
+    }
+
+    #[test]
+    fn pattern_table_oversized_header() {
+        let header = |guesses: u32, answers: u32| {
+            let mut bytes = b"WPT1".to_vec();
+            bytes.extend_from_slice(&guesses.to_le_bytes());
+            bytes.extend_from_slice(&answers.to_le_bytes());
+            bytes
+        };
+
+        // Counts far beyond the data must be rejected rather than allocated for.
+        assert!(PatternTable::from_shared(header(u32::MAX, u32::MAX)).is_err());
+        assert!(PatternTable::from_shared(header(u32::MAX, 0)).is_err());
+
+        let mut bytes = header(2, 1);
+        bytes.extend_from_slice(b"bat\ncat\n");
+        assert!(PatternTable::from_shared(bytes).is_err());
+
+        let mut bytes = header(1, 1);
+        bytes.extend_from_slice(b"bat\nbat\n");
+        let code = Feedback::score("bat", "bat").code() as u8;
+        bytes.push(code);
+        let table = PatternTable::from_shared(bytes).unwrap();
+        assert_eq!(table.get("bat", "bat"), Some(code));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pattern_table_too_long() {
+        PatternTable::new(&["crates"], &["crates"]);
+    }
+}
@@ -0,0 +1,358 @@
+use crate::alphabet::{Alphabet, English};
+use crate::feedback::{partition_with, pattern};
+use crate::{entropy, overlap, par, PatternTable};
+use std::collections::BTreeMap;
+
+/// The words that could still be the answer, as seen by a [`Scorer`].
+///
+/// If the engine has a [`PatternTable`] covering every candidate, feedback is looked up in the
+/// table rather than computed.  The candidates are written in the [`English`] alphabet unless
+/// another is given with [`Candidates::alphabet`], and are equally likely to be the answer
+/// unless given [`Candidates::weights`].
+#[derive(Clone, Debug)]
+pub struct Candidates<'a> {
+    words: &'a [&'a str],
+    weights: Option<&'a [f64]>,
+    patterns: Option<(&'a PatternTable, Vec<usize>)>,
+    letters: &'static [char],
+    index: fn(char) -> Option<usize>,
+    pattern: fn(&str, &str) -> usize,
+}
+
+impl<'a> Candidates<'a> {
+    pub const fn new(words: &'a [&'a str]) -> Self {
+        Self {
+            words,
+            weights: None,
+            patterns: None,
+            letters: English::LETTERS,
+            index: English::index,
+            pattern: pattern::<English>,
+        }
+    }
+
+    /// Uses `table` to look up feedback, provided it contains every word as an answer.
+    pub fn with_patterns(words: &'a [&'a str], table: &'a PatternTable) -> Self {
+        let indices: Option<Vec<_>> = words.iter().map(|w| table.answer_index(w)).collect();
+        Self {
+            patterns: indices.map(|indices| (table, indices)),
+            ..Self::new(words)
+        }
+    }
+
+    /// Sets the alphabet the candidates are written in.
+    pub fn alphabet<A: Alphabet>(self) -> Self {
+        Self {
+            letters: A::LETTERS,
+            index: A::index,
+            pattern: pattern::<A>,
+            ..self
+        }
+    }
+
+    /// Sets how likely each candidate is to be the answer, relative to the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't one weight per candidate.
+    pub fn weights(self, weights: &'a [f64]) -> Self {
+        assert_eq!(weights.len(), self.words.len());
+        Self {
+            weights: Some(weights),
+            ..self
+        }
+    }
+
+    /// How likely the `i`th candidate is to be the answer, relative to the others.
+    pub fn weight(&self, i: usize) -> f64 {
+        self.weights.map_or(1.0, |weights| weights[i])
+    }
+
+    /// The letters of the candidates' alphabet.
+    pub fn letters(&self) -> &'static [char] {
+        self.letters
+    }
+
+    /// The position of the letter `c` stands for within [`Candidates::letters`].
+    pub fn letter_index(&self, c: char) -> Option<usize> {
+        (self.index)(c)
+    }
+
+    pub fn words(&self) -> &[&'a str] {
+        self.words
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Partitions the candidates by the feedback `guess` would receive against each of them,
+    /// returning the size of each non-empty bucket in order of [`Feedback::code`].
+    ///
+    /// [`Feedback::code`]: crate::Feedback::code
+    pub fn buckets(&self, guess: &str) -> Vec<usize> {
+        if let Some((table, indices)) = &self.patterns {
+            if let Some(g) = table.guess_index(guess) {
+                let mut counts = [0; 256];
+                for &a in indices {
+                    counts[table.code(g, a) as usize] += 1;
+                }
+                return counts.into_iter().filter(|&n| n > 0).collect();
+            }
+        }
+
+        partition_with(self.pattern, guess, self.words)
+            .into_values()
+            .collect()
+    }
+
+    /// Like [`Candidates::buckets`], but sums the [`weight`](Candidates::weight) of the
+    /// candidates in each bucket rather than counting them.
+    pub fn weighted_buckets(&self, guess: &str) -> Vec<f64> {
+        if self.weights.is_none() {
+            return self.buckets(guess).into_iter().map(|n| n as f64).collect();
+        }
+
+        if let Some((table, indices)) = &self.patterns {
+            if let Some(g) = table.guess_index(guess) {
+                let mut sums = [0.0; 256];
+                for (i, &a) in indices.iter().enumerate() {
+                    sums[table.code(g, a) as usize] += self.weight(i);
+                }
+                return sums.into_iter().filter(|&w| w > 0.0).collect();
+            }
+        }
+
+        let mut sums: BTreeMap<usize, f64> = BTreeMap::new();
+        for (i, word) in self.words.iter().enumerate() {
+            *sums.entry((self.pattern)(guess, word)).or_default() += self.weight(i);
+        }
+        sums.into_values().filter(|&w| w > 0.0).collect()
+    }
+}
+
+/// A strategy for ranking guesses against the words that could still be the answer.
+///
+/// Higher scores are better.  Scores only need to be comparable with other scores from the
+/// same scorer and the same candidates.  With the `parallel` feature, guesses are scored on
+/// multiple threads at once.
+pub trait Scorer: Send + Sync {
+    /// Scores `guess` against the remaining `candidates`.
+    fn score(&self, guess: &str, candidates: &Candidates) -> f64;
+
+    /// Scores every guess against the remaining `candidates`, in the same order as `guesses`.
+    ///
+    /// Scorers that can share work between guesses should override this.
+    fn score_all(&self, guesses: &[&str], candidates: &Candidates) -> Vec<f64> {
+        par::map(guesses, |guess| self.score(guess, candidates))
+    }
+}
+
+impl<S: Scorer + ?Sized> Scorer for Box<S> {
+    fn score(&self, guess: &str, candidates: &Candidates) -> f64 {
+        (**self).score(guess, candidates)
+    }
+
+    fn score_all(&self, guesses: &[&str], candidates: &Candidates) -> Vec<f64> {
+        (**self).score_all(guesses, candidates)
+    }
+}
+
+/// Ranks guesses by [`Overlap::weighted`](crate::Overlap::weighted).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OverlapScorer;
+
+impl Scorer for OverlapScorer {
+    fn score(&self, guess: &str, candidates: &Candidates) -> f64 {
+        self.score_all(&[guess], candidates)[0]
+    }
+
+    fn score_all(&self, guesses: &[&str], candidates: &Candidates) -> Vec<f64> {
+        overlap::overlaps_with(
+            candidates.index,
+            candidates.letters.len(),
+            guesses,
+            candidates.words(),
+        )
+        .into_iter()
+        .map(|overlap| overlap.weighted() as f64)
+        .collect()
+    }
+}
+
+/// Ranks guesses by the expected information they reveal, see [`entropy`](crate::entropy).
+/// Weighted candidates are treated as being more or less likely to be the answer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct EntropyScorer;
+
+impl Scorer for EntropyScorer {
+    fn score(&self, guess: &str, candidates: &Candidates) -> f64 {
+        entropy::from_weights(&candidates.weighted_buckets(guess))
+    }
+}
+
+/// Ranks guesses by the number of candidates that could remain in the worst case, see
+/// [`worst_case`](crate::worst_case).  The score is the negated worst case, so that fewer is
+/// better.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MinimaxScorer;
+
+impl Scorer for MinimaxScorer {
+    fn score(&self, guess: &str, candidates: &Candidates) -> f64 {
+        -(candidates.buckets(guess).into_iter().max().unwrap_or(0) as f64)
+    }
+}
+
+/// Ranks guesses by how common their letters are among the candidates, ignoring position.
+///
+/// Each distinct letter in the guess contributes the fraction of candidates that contain it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrequencyScorer;
+
+impl FrequencyScorer {
+    fn frequencies(candidates: &Candidates) -> Vec<f64> {
+        let mut counts = vec![0usize; candidates.letters().len()];
+        for word in candidates.words() {
+            for i in letters(word, candidates) {
+                counts[i] += 1;
+            }
+        }
+
+        let total = candidates.len().max(1) as f64;
+        counts.into_iter().map(|c| c as f64 / total).collect()
+    }
+
+    fn score_with(guess: &str, candidates: &Candidates, frequencies: &[f64]) -> f64 {
+        letters(guess, candidates).map(|i| frequencies[i]).sum()
+    }
+}
+
+impl Scorer for FrequencyScorer {
+    fn score(&self, guess: &str, candidates: &Candidates) -> f64 {
+        Self::score_with(guess, candidates, &Self::frequencies(candidates))
+    }
+
+    fn score_all(&self, guesses: &[&str], candidates: &Candidates) -> Vec<f64> {
+        let frequencies = Self::frequencies(candidates);
+        guesses
+            .iter()
+            .map(|guess| Self::score_with(guess, candidates, &frequencies))
+            .collect()
+    }
+}
+
+/// The distinct letters of `word`, as indices into the candidates' alphabet.
+fn letters<'a>(word: &str, candidates: &Candidates) -> impl Iterator<Item = usize> + 'a {
+    let mut seen = vec![false; candidates.letters().len()];
+    for i in word.chars().filter_map(|c| candidates.letter_index(c)) {
+        seen[i] = true;
+    }
+    (0..seen.len()).filter(move |&i| seen[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Candidates, EntropyScorer, FrequencyScorer, MinimaxScorer, OverlapScorer, Scorer};
+    use crate::alphabet::Spanish;
+    use crate::PatternTable;
+
+    const WORDS: Candidates = Candidates::new(&["bat", "cat", "hat", "dot"]);
+
+    #[test]
+    fn candidates_buckets() {
+        let words = ["bat", "cat", "hat", "dot"];
+        let table = PatternTable::new(&["bat", "chb"], &words);
+
+        for candidates in [
+            Candidates::new(&words),
+            Candidates::with_patterns(&words, &table),
+        ] {
+            let mut buckets = candidates.buckets("bat");
+            buckets.sort_unstable();
+            assert_eq!(buckets, [1, 1, 2]);
+
+            // `dot` isn't a guess in the table, so its feedback has to be computed.
+            let mut buckets = candidates.buckets("dot");
+            buckets.sort_unstable();
+            assert_eq!(buckets, [1, 3]);
+        }
+    }
+
+    #[test]
+    fn candidates_alphabet() {
+        let words = ["canto", "cinto"];
+
+        // In Spanish the accent on `á` is ignored, so `cánto` tells the words apart.
+        let spanish = Candidates::new(&words).alphabet::<Spanish>();
+        assert_eq!(spanish.buckets("cánto"), [1, 1]);
+        assert_eq!(MinimaxScorer.score("cánto", &spanish), -1.0);
+        assert_eq!(Candidates::new(&words).buckets("cánto"), [2]);
+    }
+
+    #[test]
+    fn candidates_weighted_buckets() {
+        let words = ["bat", "cat", "hat", "dot"];
+        let weights = [1.0, 2.0, 3.0, 4.0];
+        let table = PatternTable::new(&["bat"], &words);
+
+        for candidates in [
+            Candidates::new(&words),
+            Candidates::with_patterns(&words, &table),
+        ] {
+            let candidates = candidates.weights(&weights);
+            let mut buckets = candidates.weighted_buckets("bat");
+            buckets.sort_by(f64::total_cmp);
+            assert_eq!(buckets, [1.0, 4.0, 5.0]);
+        }
+
+        // Unweighted candidates all weigh the same.
+        let mut buckets = Candidates::new(&words).weighted_buckets("bat");
+        buckets.sort_by(f64::total_cmp);
+        assert_eq!(buckets, [1.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn overlap_scorer() {
+        // `bat` has a total overlap of 8 and a partial overlap of 8.
+        assert_eq!(OverlapScorer.score("bat", &WORDS), 10.0);
+        assert_eq!(
+            OverlapScorer.score_all(&["bat", "dot"], &WORDS),
+            [
+                OverlapScorer.score("bat", &WORDS),
+                OverlapScorer.score("dot", &WORDS)
+            ]
+        );
+    }
+
+    #[test]
+    fn entropy_scorer() {
+        assert!((EntropyScorer.score("bat", &WORDS) - 1.5).abs() < 1e-9);
+
+        // `dot` is almost certainly the answer, so there's little to learn by splitting the rest.
+        let weighted = WORDS.weights(&[1.0, 1.0, 1.0, 997.0]);
+        assert!(EntropyScorer.score("bat", &weighted) < 0.1);
+    }
+
+    #[test]
+    fn minimax_scorer() {
+        assert_eq!(MinimaxScorer.score("bat", &WORDS), -2.0);
+        assert_eq!(MinimaxScorer.score("dot", &WORDS), -3.0);
+    }
+
+    #[test]
+    fn frequency_scorer() {
+        // `b` appears in a quarter of the words, `a` in three quarters and `t` in all of them.
+        assert_eq!(FrequencyScorer.score("bat", &WORDS), 2.0);
+
+        // Repeated letters only count once.
+        assert_eq!(FrequencyScorer.score("ttt", &WORDS), 1.0);
+        assert_eq!(
+            FrequencyScorer.score_all(&["bat", "ttt"], &WORDS),
+            [2.0, 1.0]
+        );
+    }
+}
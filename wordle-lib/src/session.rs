@@ -0,0 +1,244 @@
+use crate::alphabet::{Alphabet, English};
+use crate::{par, Contradiction, DynEngine, Feedback, Inference, Knowledge};
+use std::sync::Arc;
+
+/// What was known after a turn of a [`Session`].
+struct Step<'w, A: Alphabet> {
+    /// The inferences added this turn.
+    inferences: Vec<Inference<A>>,
+    /// Everything known after this turn.
+    knowledge: Knowledge<A>,
+    /// The words which could still be the answer after this turn.
+    candidates: Vec<&'w str>,
+}
+
+/// A game in progress, which keeps track of the words that could still be the answer.
+///
+/// Each turn only filters the candidates left by the turn before, rather than every word the
+/// engine knows.  Turns can be undone, and since every turn is shared rather than copied, a
+/// [`snapshot`](Session::snapshot) of the session is cheap enough to explore "what-if" lines.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{DynEngine, Feedback, Session};
+///
+/// let engine = DynEngine::new(3, ["bat", "cat", "hat", "dot", "dog"]);
+/// let mut session = Session::new(engine);
+///
+/// session.apply("cat", &Feedback::score("cat", "hat")).unwrap();
+/// assert_eq!(session.candidates(), ["bat", "hat"]);
+///
+/// let mut what_if = session.snapshot();
+/// what_if.apply("bat", &Feedback::score("bat", "hat")).unwrap();
+/// assert_eq!(what_if.candidates(), ["hat"]);
+///
+/// session.undo();
+/// assert_eq!(session.candidates().len(), 5);
+/// ```
+pub struct Session<'w, A: Alphabet = English> {
+    engine: Arc<DynEngine<'w, A>>,
+    /// The state before the first turn, followed by the state after each turn.
+    steps: Vec<Arc<Step<'w, A>>>,
+}
+
+impl<'w, A: Alphabet> Clone for Session<'w, A> {
+    fn clone(&self) -> Self {
+        Self {
+            engine: Arc::clone(&self.engine),
+            steps: self.steps.clone(),
+        }
+    }
+}
+
+impl<'w, A: Alphabet> Session<'w, A> {
+    /// Starts a session in which every word known to `engine` could be the answer.
+    pub fn new<E: Into<Arc<DynEngine<'w, A>>>>(engine: E) -> Self {
+        let engine = engine.into();
+        let root = Step {
+            inferences: Vec::new(),
+            knowledge: Knowledge::new_in(),
+            candidates: engine.candidates(&[]),
+        };
+
+        Self {
+            engine,
+            steps: vec![Arc::new(root)],
+        }
+    }
+
+    pub fn engine(&self) -> &DynEngine<'w, A> {
+        &self.engine
+    }
+
+    fn last(&self) -> &Step<'w, A> {
+        self.steps.last().unwrap()
+    }
+
+    /// The number of turns played.
+    pub fn len(&self) -> usize {
+        self.steps.len() - 1
+    }
+
+    /// Returns `true` if no turns have been played.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The inferences added on the given turn, counting from zero.
+    pub fn turn(&self, turn: usize) -> Option<&[Inference<A>]> {
+        self.steps
+            .get(turn + 1)
+            .map(|step| step.inferences.as_slice())
+    }
+
+    /// Everything known about the answer so far.
+    pub fn knowledge(&self) -> &Knowledge<A> {
+        &self.last().knowledge
+    }
+
+    /// The words which could still be the answer.
+    pub fn candidates(&self) -> &[&'w str] {
+        &self.last().candidates
+    }
+
+    /// Plays `guess`, which received `feedback`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `guess` and `feedback` have different lengths.
+    pub fn apply(&mut self, guess: &str, feedback: &Feedback) -> Result<(), Contradiction<A>> {
+        self.apply_inferences(feedback.inferences_in(guess))
+    }
+
+    /// Plays a turn which revealed `inferences`, such as a partly filled in board.  If they
+    /// contradict an earlier turn, the session is left unchanged.
+    pub fn apply_inferences(
+        &mut self,
+        inferences: Vec<Inference<A>>,
+    ) -> Result<(), Contradiction<A>> {
+        let last = self.last();
+
+        let mut knowledge = last.knowledge.clone();
+        knowledge.add_all(self.len(), inferences.iter().copied())?;
+
+        let candidates = par::filter(&last.candidates, |&w| {
+            inferences.iter().all(|i| i.filter(w))
+        });
+
+        self.steps.push(Arc::new(Step {
+            inferences,
+            knowledge,
+            candidates,
+        }));
+        Ok(())
+    }
+
+    /// Takes back the last turn, returning the inferences it added, or `None` if no turns
+    /// have been played.
+    pub fn undo(&mut self) -> Option<Vec<Inference<A>>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let step = self.steps.pop().unwrap();
+        Some(match Arc::try_unwrap(step) {
+            Ok(step) => step.inferences,
+            Err(step) => step.inferences.clone(),
+        })
+    }
+
+    /// Takes back every turn after the first `turns`.
+    pub fn truncate(&mut self, turns: usize) {
+        self.steps.truncate(turns + 1);
+    }
+
+    /// Copies the session, so that it can be played on without affecting this one.  The turns
+    /// played so far are shared between the two.
+    pub fn snapshot(&self) -> Self {
+        self.clone()
+    }
+
+    /// Ranks the remaining candidates, like [`DynEngine::rank`].
+    pub fn rank(&self) -> Vec<(&'w str, f64)> {
+        let candidates = self.candidates();
+        self.engine.rank_guesses(candidates, candidates)
+    }
+
+    /// Ranks every legal guess against the remaining candidates, like
+    /// [`DynEngine::rank_probes`].
+    pub fn rank_probes(&self) -> Vec<(&'w str, f64)> {
+        let guesses = self.engine.guesses(&self.knowledge().inferences());
+        self.engine.rank_guesses(&guesses, self.candidates())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+    use crate::{DynEngine, Feedback, Inference, InferenceKind};
+
+    const WORDS: [&str; 6] = ["bat", "cat", "hat", "mat", "dot", "dog"];
+
+    #[test]
+    fn session_matches_engine() {
+        let engine = DynEngine::new(3, WORDS);
+        let mut session = Session::new(DynEngine::new(3, WORDS));
+
+        let mut inferences = Vec::new();
+        for guess in ["dog", "cat"] {
+            let feedback = Feedback::score(guess, "mat");
+            session.apply(guess, &feedback).unwrap();
+            inferences.extend(feedback.inferences(guess));
+
+            assert_eq!(session.candidates(), engine.candidates(&inferences));
+            assert_eq!(session.rank(), engine.rank(&inferences));
+            assert_eq!(session.rank_probes(), engine.rank_probes(&inferences));
+        }
+
+        assert_eq!(session.len(), 2);
+        assert_eq!(session.candidates(), ["bat", "hat", "mat"]);
+    }
+
+    #[test]
+    fn session_undo_and_snapshot() {
+        let mut session = Session::new(DynEngine::new(3, WORDS));
+        assert_eq!(session.undo(), None);
+
+        session
+            .apply("dog", &Feedback::score("dog", "hat"))
+            .unwrap();
+        let snapshot = session.snapshot();
+
+        session
+            .apply("cat", &Feedback::score("cat", "hat"))
+            .unwrap();
+        assert_eq!(session.candidates(), ["bat", "hat", "mat"]);
+
+        // The snapshot doesn't see turns played after it was taken.
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot.candidates(), ["bat", "cat", "hat", "mat"]);
+
+        let undone = session.undo().unwrap();
+        assert_eq!(undone, Feedback::score("cat", "hat").inferences("cat"));
+        assert_eq!(session.candidates(), snapshot.candidates());
+
+        session.truncate(0);
+        assert!(session.is_empty());
+        assert_eq!(session.candidates().len(), WORDS.len());
+    }
+
+    #[test]
+    fn session_contradiction() {
+        let mut session = Session::new(DynEngine::new(3, WORDS));
+        session
+            .apply_inferences(vec![Inference::new('a', 1, InferenceKind::Correct)])
+            .unwrap();
+
+        let contradiction = session
+            .apply_inferences(vec![Inference::new('a', 0, InferenceKind::AbsentGlobal)])
+            .unwrap_err();
+        assert_eq!(contradiction.boards(), [0, 1]);
+        assert_eq!(session.len(), 1);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::alphabet::Alphabet;
+use crate::{DynEngine, Feedback, Inference};
+use std::collections::BTreeMap;
+
+/// The outcome of playing a strategy against every answer in a word list.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report<'w> {
+    /// The number of guesses taken to find each answer, or `None` if it wasn't found within
+    /// the guess limit.
+    pub results: Vec<(&'w str, Option<usize>)>,
+}
+
+impl<'w> Report<'w> {
+    /// The number of answers found with each number of guesses.
+    pub fn histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for (_, guesses) in &self.results {
+            if let Some(guesses) = guesses {
+                *histogram.entry(*guesses).or_default() += 1;
+            }
+        }
+        histogram
+    }
+
+    /// The average number of guesses taken to find an answer, ignoring failures.
+    pub fn mean(&self) -> f64 {
+        let solved: Vec<_> = self.results.iter().filter_map(|(_, g)| *g).collect();
+        if solved.is_empty() {
+            0.0
+        } else {
+            solved.iter().sum::<usize>() as f64 / solved.len() as f64
+        }
+    }
+
+    /// The answers which weren't found within the guess limit.
+    pub fn failures(&self) -> Vec<&'w str> {
+        self.results
+            .iter()
+            .filter(|(_, g)| g.is_none())
+            .map(|(w, _)| *w)
+            .collect()
+    }
+
+    /// The `n` answers which took the most guesses to find, ignoring failures.
+    pub fn slowest(&self, n: usize) -> Vec<(&'w str, usize)> {
+        let mut solved: Vec<_> = self
+            .results
+            .iter()
+            .filter_map(|&(w, g)| g.map(|g| (w, g)))
+            .collect();
+        solved.sort_by_key(|&(w, g)| (std::cmp::Reverse(g), w));
+        solved.truncate(n);
+        solved
+    }
+}
+
+/// Plays `strategy` against every word in `answers`, allowing at most `max_guesses` guesses
+/// for each.
+///
+/// Each turn the strategy is given the engine and everything inferred so far, and returns the
+/// word to guess next (or `None` to give up).
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::{simulate, Engine};
+///
+/// let words = ["bat", "cat", "hat", "dot", "dog"];
+/// let engine = Engine::<3>::new(words);
+///
+/// let report = simulate::simulate(&engine, &words, 6, simulate::best);
+///
+/// assert_eq!(report.failures().len(), 0);
+/// assert_eq!(report.histogram().values().sum::<usize>(), 5);
+/// ```
+pub fn simulate<'w, A: Alphabet, F>(
+    engine: &DynEngine<'w, A>,
+    answers: &[&'w str],
+    max_guesses: usize,
+    mut strategy: F,
+) -> Report<'w>
+where
+    F: FnMut(&DynEngine<'w, A>, &[Inference<A>]) -> Option<&'w str>,
+{
+    let results = answers
+        .iter()
+        .map(|&answer| (answer, play(engine, answer, max_guesses, &mut strategy)))
+        .collect();
+
+    Report { results }
+}
+
+fn play<'w, A: Alphabet, F>(
+    engine: &DynEngine<'w, A>,
+    answer: &'w str,
+    max_guesses: usize,
+    strategy: &mut F,
+) -> Option<usize>
+where
+    F: FnMut(&DynEngine<'w, A>, &[Inference<A>]) -> Option<&'w str>,
+{
+    let mut inferences: Vec<Inference<A>> = Vec::new();
+
+    for turn in 1..=max_guesses {
+        let guess = strategy(engine, &inferences)?;
+        let feedback = Feedback::score_in::<A, _, _>(guess, answer);
+        if feedback.is_solved() {
+            return Some(turn);
+        }
+
+        for inference in feedback.inferences_in(guess) {
+            if !inferences.contains(&inference) {
+                inferences.push(inference);
+            }
+        }
+    }
+
+    None
+}
+
+/// Guesses the valid word ranked highest by the engine's [`Scorer`](crate::Scorer).
+pub fn best<'w, A: Alphabet>(engine: &DynEngine<'w, A>, inf: &[Inference<A>]) -> Option<&'w str> {
+    engine.rank(inf).first().map(|&(w, _)| w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{best, simulate, Report};
+    use crate::scorer::{EntropyScorer, MinimaxScorer};
+    use crate::Engine;
+
+    const WORDS: [&str; 6] = ["bat", "cat", "hat", "mat", "dot", "dog"];
+
+    #[test]
+    fn simulate_fixed_guesses() {
+        let engine = Engine::<3>::new(WORDS);
+
+        // Always guessing `cat` only ever finds `cat`.
+        let report = simulate(&engine, &WORDS, 3, |_, _| Some("cat"));
+
+        assert_eq!(report.histogram().into_iter().collect::<Vec<_>>(), [(1, 1)]);
+        assert_eq!(report.failures(), ["bat", "hat", "mat", "dot", "dog"]);
+    }
+
+    #[test]
+    fn simulate_strategies() {
+        let engines = [
+            Engine::<3>::new(WORDS),
+            Engine::<3>::new(WORDS).scorer(EntropyScorer),
+            Engine::<3>::new(WORDS).scorer(MinimaxScorer),
+        ];
+
+        for engine in engines {
+            let report = simulate(&engine, &WORDS, 6, best);
+            assert!(report.failures().is_empty());
+            assert_eq!(report.results.len(), WORDS.len());
+        }
+    }
+
+    #[test]
+    fn report_stats() {
+        let report = Report {
+            results: vec![
+                ("bat", Some(2)),
+                ("cat", Some(4)),
+                ("hat", None),
+                ("mat", Some(3)),
+                ("dot", Some(3)),
+            ],
+        };
+
+        assert_eq!(
+            report.histogram().into_iter().collect::<Vec<_>>(),
+            [(2, 1), (3, 2), (4, 1)]
+        );
+        assert!((report.mean() - 3.0).abs() < 1e-9);
+        assert_eq!(report.failures(), ["hat"]);
+        assert_eq!(report.slowest(2), [("cat", 4), ("dot", 3)]);
+    }
+}
@@ -0,0 +1,389 @@
+use crate::alphabet::English;
+use crate::feedback::pattern;
+use crate::{entropy, worst_case, Feedback};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// A complete strategy for finding any word in an answer list.
+///
+/// Each [`Node`] holds the word to guess next, and each of its branches is labelled with the
+/// feedback that leads there.  After the starting word, every guess is the remaining candidate
+/// which reveals the most information about the answer (see [`entropy`]), with ties broken by
+/// the smaller [`worst_case`] and then alphabetically.
+///
+/// # Example
+///
+/// ```rust
+/// use wordle_lib::DecisionTree;
+///
+/// let answers = ["bat", "cat", "hat", "cot", "dog"];
+/// let tree = DecisionTree::build("cat", &answers, 6);
+///
+/// let stats = tree.stats();
+/// assert_eq!(stats.solved, 5);
+/// assert_eq!(stats.failed, 0);
+/// assert_eq!(stats.max_depth, 3);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecisionTree {
+    root: Node,
+}
+
+/// A guess within a [`DecisionTree`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    guess: &'static str,
+    candidates: usize,
+    branches: Vec<(Feedback, Branch)>,
+}
+
+/// What happens after a guess receives a particular feedback.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Branch {
+    /// The guess was the answer.
+    Solved,
+    /// The answer is one of several candidates, to be narrowed down by the next guess.
+    Node(Node),
+    /// The guess limit was reached without finding any of these answers.
+    Failed(Vec<&'static str>),
+}
+
+/// Summary statistics for a [`DecisionTree`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of answers found within the guess limit.
+    pub solved: usize,
+    /// The number of answers not found within the guess limit.
+    pub failed: usize,
+    /// The total number of guesses taken to find every solved answer.
+    pub total_guesses: usize,
+    /// The most guesses taken to find any solved answer.
+    pub max_depth: usize,
+}
+
+impl Stats {
+    /// The average number of guesses taken to find a solved answer.
+    pub fn average_guesses(&self) -> f64 {
+        if self.solved == 0 {
+            0.0
+        } else {
+            self.total_guesses as f64 / self.solved as f64
+        }
+    }
+}
+
+impl DecisionTree {
+    /// Builds the strategy that opens with `start` and must find every word in `answers`
+    /// within `max_guesses` guesses.
+    pub fn build(start: &'static str, answers: &[&'static str], max_guesses: usize) -> Self {
+        Self {
+            root: Node::build(start, answers, 1, max_guesses),
+        }
+    }
+
+    pub fn root(&self) -> &Node {
+        &self.root
+    }
+
+    pub fn stats(&self) -> Stats {
+        let mut stats = Stats::default();
+        self.root.collect_stats(1, &mut stats);
+        stats
+    }
+
+    /// Exports the tree as JSON.  Each node is an object with its `guess`, the number of
+    /// `candidates` remaining before it is played, and a list of `branches`.  Each branch has
+    /// the `feedback` leading to it (see [`Feedback`]'s `Display` implementation) and exactly
+    /// one of `"solved": true`, a `node`, or the list of `failed` answers.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.root.write_json(&mut out);
+        out
+    }
+
+    /// Exports the tree in the Graphviz DOT language.  Guesses which could be the answer are
+    /// drawn with a double outline, and answers which could not be found are drawn in red.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph strategy {\n");
+        let mut next_id = 0;
+        self.root.write_dot(&mut out, &mut next_id);
+        out.push_str("}\n");
+        out
+    }
+}
+
+impl Node {
+    fn build(
+        guess: &'static str,
+        candidates: &[&'static str],
+        depth: usize,
+        max_guesses: usize,
+    ) -> Self {
+        let mut buckets: BTreeMap<usize, Vec<&'static str>> = BTreeMap::new();
+        for &candidate in candidates {
+            buckets
+                .entry(pattern::<English>(guess, candidate))
+                .or_default()
+                .push(candidate);
+        }
+
+        let branches = buckets
+            .into_values()
+            .map(|bucket| {
+                let feedback = Feedback::score(guess, bucket[0]);
+                let branch = if feedback.is_solved() {
+                    Branch::Solved
+                } else if depth >= max_guesses {
+                    Branch::Failed(bucket)
+                } else {
+                    let next = best_guess(&bucket);
+                    Branch::Node(Node::build(next, &bucket, depth + 1, max_guesses))
+                };
+                (feedback, branch)
+            })
+            .collect();
+
+        Self {
+            guess,
+            candidates: candidates.len(),
+            branches,
+        }
+    }
+
+    pub fn guess(&self) -> &'static str {
+        self.guess
+    }
+
+    /// The number of answers still possible before this guess is played.
+    pub fn candidates(&self) -> usize {
+        self.candidates
+    }
+
+    pub fn branches(&self) -> &[(Feedback, Branch)] {
+        &self.branches
+    }
+
+    fn collect_stats(&self, depth: usize, stats: &mut Stats) {
+        for (_, branch) in &self.branches {
+            match branch {
+                Branch::Solved => {
+                    stats.solved += 1;
+                    stats.total_guesses += depth;
+                    stats.max_depth = stats.max_depth.max(depth);
+                }
+                Branch::Node(node) => node.collect_stats(depth + 1, stats),
+                Branch::Failed(words) => stats.failed += words.len(),
+            }
+        }
+    }
+
+    fn write_json(&self, out: &mut String) {
+        write!(
+            out,
+            "{{\"guess\":{},\"candidates\":{},\"branches\":[",
+            json_string(self.guess),
+            self.candidates
+        )
+        .unwrap();
+
+        for (i, (feedback, branch)) in self.branches.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, "{{\"feedback\":\"{}\",", feedback).unwrap();
+            match branch {
+                Branch::Solved => out.push_str("\"solved\":true"),
+                Branch::Node(node) => {
+                    out.push_str("\"node\":");
+                    node.write_json(out);
+                }
+                Branch::Failed(words) => {
+                    let words: Vec<_> = words.iter().map(|w| json_string(w)).collect();
+                    write!(out, "\"failed\":[{}]", words.join(",")).unwrap();
+                }
+            }
+            out.push('}');
+        }
+
+        out.push_str("]}");
+    }
+
+    fn write_dot(&self, out: &mut String, next_id: &mut usize) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+
+        let peripheries = if self.branches.iter().any(|(_, b)| *b == Branch::Solved) {
+            2
+        } else {
+            1
+        };
+        writeln!(
+            out,
+            "    n{} [label={}, peripheries={}];",
+            id,
+            json_string(self.guess),
+            peripheries
+        )
+        .unwrap();
+
+        for (feedback, branch) in &self.branches {
+            let child = match branch {
+                Branch::Solved => continue,
+                Branch::Node(node) => node.write_dot(out, next_id),
+                Branch::Failed(words) => {
+                    let child = *next_id;
+                    *next_id += 1;
+                    let words: Vec<_> = words.iter().map(|w| escape(w)).collect();
+                    writeln!(
+                        out,
+                        "    n{} [label=\"{}\", shape=box, color=red];",
+                        child,
+                        words.join("\\n")
+                    )
+                    .unwrap();
+                    child
+                }
+            };
+            writeln!(out, "    n{} -> n{} [label=\"{}\"];", id, child, feedback).unwrap();
+        }
+
+        id
+    }
+}
+
+/// Picks the candidate which reveals the most about the answer, as described on
+/// [`DecisionTree`].
+fn best_guess(candidates: &[&'static str]) -> &'static str {
+    candidates
+        .iter()
+        .map(|&w| (w, entropy(w, candidates), worst_case(w, candidates)))
+        .max_by(|(lw, le, lc), (rw, re, rc)| {
+            le.partial_cmp(re)
+                .unwrap()
+                .then(rc.cmp(lc))
+                .then(rw.cmp(lw))
+        })
+        .map(|(w, _, _)| w)
+        .unwrap()
+}
+
+/// Quotes `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+/// Escapes `s` for use within a JSON (or DOT) string literal.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Branch, DecisionTree};
+
+    const ANSWERS: [&str; 5] = ["bat", "cat", "hat", "cot", "dog"];
+
+    #[test]
+    fn tree_structure() {
+        let tree = DecisionTree::build("cat", &ANSWERS, 6);
+        let root = tree.root();
+
+        assert_eq!(root.guess(), "cat");
+        assert_eq!(root.candidates(), 5);
+
+        // [B, B, B] for `dog`, [B, G, G] for `bat`/`hat`, [G, B, G] for `cot`, [G, G, G].
+        let feedback: Vec<_> = root.branches().iter().map(|(f, _)| f.to_string()).collect();
+        assert_eq!(feedback, ["BBB", "BGG", "GBG", "GGG"]);
+        assert_eq!(root.branches()[3].1, Branch::Solved);
+
+        match &root.branches()[1].1 {
+            Branch::Node(node) => {
+                assert_eq!(node.guess(), "bat");
+                assert_eq!(node.candidates(), 2);
+            }
+            branch => panic!("unexpected branch {:?}", branch),
+        }
+    }
+
+    #[test]
+    fn tree_stats() {
+        let stats = DecisionTree::build("cat", &ANSWERS, 6).stats();
+
+        // `cat` in one, `dog`, `cot` and `bat` in two, `hat` in three.
+        assert_eq!(stats.solved, 5);
+        assert_eq!(stats.total_guesses, 10);
+        assert_eq!(stats.max_depth, 3);
+        assert!((stats.average_guesses() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tree_failures() {
+        // With only two guesses, `hat` can't be found.
+        let tree = DecisionTree::build("cat", &ANSWERS, 2);
+        let stats = tree.stats();
+
+        assert_eq!(stats.solved, 4);
+        assert_eq!(stats.failed, 1);
+        assert!(tree.to_json().contains("\"failed\":[\"hat\"]"));
+    }
+
+    #[test]
+    fn tree_is_deterministic() {
+        let answers: Vec<_> = wordle_dict::ANSWERS.iter().copied().collect();
+        let first = DecisionTree::build("crane", &answers, 6);
+        let second = DecisionTree::build("crane", &answers, 6);
+
+        assert_eq!(first.stats(), second.stats());
+        assert_eq!(first.to_json(), second.to_json());
+    }
+
+    #[test]
+    fn tree_json() {
+        let tree = DecisionTree::build("cat", &["cat", "cot"], 6);
+
+        assert_eq!(
+            tree.to_json(),
+            concat!(
+                r#"{"guess":"cat","candidates":2,"branches":["#,
+                r#"{"feedback":"GBG","node":{"guess":"cot","candidates":1,"branches":["#,
+                r#"{"feedback":"GGG","solved":true}]}},"#,
+                r#"{"feedback":"GGG","solved":true}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn tree_dot_failures() {
+        let tree = DecisionTree::build("cat", &["bat", "hat"], 1);
+
+        assert!(tree
+            .to_dot()
+            .contains("    n1 [label=\"bat\\nhat\", shape=box, color=red];\n"));
+    }
+
+    #[test]
+    fn tree_dot() {
+        let tree = DecisionTree::build("cat", &["cat", "cot"], 6);
+
+        assert_eq!(
+            tree.to_dot(),
+            concat!(
+                "digraph strategy {\n",
+                "    n0 [label=\"cat\", peripheries=2];\n",
+                "    n1 [label=\"cot\", peripheries=2];\n",
+                "    n0 -> n1 [label=\"GBG\"];\n",
+                "}\n"
+            )
+        );
+    }
+}
@@ -8,6 +8,8 @@ use crate::worker::{Worker, WorkerInput, WorkerOutput};
 use model::Board;
 use tile::Tile;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{EventTarget, HtmlInputElement};
 use yew::prelude::*;
 use yew_agent::{Bridge, Bridged, Threaded};
 
@@ -15,14 +17,21 @@ pub enum BoardMsg {
     Toggle(usize),
     Change(usize, Option<char>),
     AddBoard,
+    ToggleHardMode,
+    WhyNot(String),
     RunWorker,
     WorkerMsg(WorkerOutput),
 }
 
 pub struct Model {
     boards: Vec<Board>,
+    hard_mode: bool,
     worker: Box<dyn Bridge<Worker>>,
     outputs: Vec<String>,
+    probes: Vec<String>,
+    error: Option<String>,
+    why_not: String,
+    reasons: Vec<String>,
 }
 
 impl Component for Model {
@@ -39,8 +48,13 @@ impl Component for Model {
 
         Self {
             boards,
+            hard_mode: false,
             worker,
             outputs: Vec::new(),
+            probes: Vec::new(),
+            error: None,
+            why_not: String::new(),
+            reasons: Vec::new(),
         }
     }
 
@@ -76,14 +90,38 @@ impl Component for Model {
                 }
                 false
             }
+            BoardMsg::ToggleHardMode => {
+                self.hard_mode = !self.hard_mode;
+                ctx.link().send_message(BoardMsg::RunWorker);
+                true
+            }
+            BoardMsg::WhyNot(word) => {
+                self.why_not = word.trim().to_string();
+                ctx.link().send_message(BoardMsg::RunWorker);
+                false
+            }
             BoardMsg::RunWorker => {
+                let why_not = Some(self.why_not.clone()).filter(|w| !w.is_empty());
+                // Every board has the same length as the word being solved.
+                let len = self.boards.first().map_or(5, |board| board.tiles.len());
                 self.worker.send(WorkerInput {
-                    boards: self.boards.clone(),
+                    len,
+                    boards: self.boards.iter().map(Board::inferences).collect(),
+                    hard_mode: self.hard_mode,
+                    why_not,
                 });
                 false
             }
-            BoardMsg::WorkerMsg(WorkerOutput { values }) => {
+            BoardMsg::WorkerMsg(WorkerOutput {
+                values,
+                probes,
+                error,
+                reasons,
+            }) => {
                 self.outputs = values;
+                self.probes = probes;
+                self.error = error;
+                self.reasons = reasons;
                 true
             }
         }
@@ -96,7 +134,7 @@ impl Component for Model {
 
         // Split out the last board from the rest.
         let (last, boards) = self.boards.split_last().unwrap();
-        for tile_state in boards.iter().map(|b| &b.tiles).flatten() {
+        for tile_state in boards.iter().flat_map(|b| &b.tiles) {
             let props = TileProps {
                 state: tile_state.mode,
                 _entry: tile_state.char,
@@ -109,13 +147,8 @@ impl Component for Model {
         }
 
         for (i, tile_state) in last.tiles.iter().enumerate() {
-            let active = if self.boards.len() > 1
-                && self.boards[self.boards.len() - 2].tiles[i].mode == TileMode::Correct
-            {
-                false
-            } else {
-                true
-            };
+            let active = !(self.boards.len() > 1
+                && self.boards[self.boards.len() - 2].tiles[i].mode == TileMode::Correct);
 
             let props = TileProps {
                 state: tile_state.mode,
@@ -127,7 +160,31 @@ impl Component for Model {
             tiles.push(html! { <Tile ..props />});
         }
 
-        let outputs = self.outputs.clone();
+        let word_list = |words: &[String]| {
+            words
+                .iter()
+                .map(|w| {
+                    html! {
+                        <li key={w.as_str()}>{ w.as_str() }</li>
+                    }
+                })
+                .collect::<Html>()
+        };
+
+        let on_why_not = link.batch_callback(|e: Event| {
+            let target: Option<EventTarget> = e.target();
+
+            let input = target.and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
+            input.map(|input| BoardMsg::WhyNot(input.value()))
+        });
+
+        let why_not = if self.why_not.is_empty() {
+            html! {}
+        } else if self.reasons.is_empty() {
+            html! { <p>{ format!("{} could still be the answer", self.why_not) }</p> }
+        } else {
+            html! { <ul class="item-list">{ word_list(&self.reasons) }</ul> }
+        };
 
         html! {
             <div class="App">
@@ -138,16 +195,33 @@ impl Component for Model {
                 </div>
                 <div>
                     <button onclick={link.callback(|_| BoardMsg::AddBoard)}>{ "Next" }</button>
+                    <label>
+                        <input
+                            type="checkbox"
+                            checked={ self.hard_mode }
+                            onchange={ link.callback(|_| BoardMsg::ToggleHardMode) }
+                        />
+                        { "Hard mode" }
+                    </label>
+                </div>
+                if let Some(error) = &self.error {
+                    <p class="error">{ error }</p>
+                }
+                <div class="suggestions">
+                    <div>
+                        <h3>{ "Best possible answer" }</h3>
+                        <ul class="item-list">{ word_list(&self.outputs) }</ul>
+                    </div>
+                    <div>
+                        <h3>{ "Best probe" }</h3>
+                        <ul class="item-list">{ word_list(&self.probes) }</ul>
+                    </div>
+                    <div>
+                        <h3>{ "Why not?" }</h3>
+                        <input type="text" class="why-not" onchange={ on_why_not } />
+                        { why_not }
+                    </div>
                 </div>
-                <ul class="item-list">
-                    {
-                        outputs.into_iter().map(|w| {
-                            html!{
-                                <li key={w.as_str()}>{ w.as_str() }</li>
-                            }
-                        }).collect::<Html>()
-                    }
-                </ul>
             </div>
         }
     }
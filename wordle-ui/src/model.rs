@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use wordle_lib::{feedback, Inference, Mark};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum TileMode {
@@ -18,6 +19,16 @@ impl TileMode {
     }
 }
 
+impl From<TileMode> for Mark {
+    fn from(mode: TileMode) -> Self {
+        match mode {
+            TileMode::Correct => Mark::Correct,
+            TileMode::Absent => Mark::Absent,
+            TileMode::Present => Mark::Present,
+        }
+    }
+}
+
 impl std::fmt::Display for TileMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -43,6 +54,18 @@ pub struct Board {
     pub tiles: Vec<TileState>,
 }
 
+impl Board {
+    /// Everything the filled in tiles reveal about the answer.
+    pub fn inferences(&self) -> Vec<Inference> {
+        let tiles = self
+            .tiles
+            .iter()
+            .enumerate()
+            .filter_map(|(i, tile)| tile.char.map(|c| (i, c, tile.mode.into())));
+        feedback::infer(tiles)
+    }
+}
+
 impl Default for Board {
     fn default() -> Self {
         Self {
@@ -81,7 +81,7 @@ impl Component for Tile {
                 <input
                     class="tile"
                     data-state={ ctx.props().state.to_string() }
-                    value={ ctx.props()._entry.map(String::from).unwrap_or_else(String::new) }
+                    value={ ctx.props()._entry.map(String::from).unwrap_or_default() }
                     onchange={ on_value_change }
                     maxlength={ max_length.to_string() }
                     disabled= {!ctx.props().active }
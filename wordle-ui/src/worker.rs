@@ -1,85 +1,89 @@
-use crate::model::{Board, TileMode};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
-use wordle_lib::{Engine, Inference, InferenceKind};
+use wordle_lib::{Contradiction, DynEngine, Inference, Session};
 use yew_agent::{Agent, AgentLink, HandlerId, Public};
 
-fn determine_inferences(boards: &[Board]) -> Vec<Inference> {
-    let mut inferences = HashSet::new();
-
-    for board in boards {
-        let mut states = HashMap::new();
-
-        for (i, tile) in board.tiles.iter().enumerate() {
-            if let Some(c) = tile.char {
-                match tile.mode {
-                    TileMode::Correct => {
-                        inferences.insert(Inference::new(c, i, InferenceKind::Correct));
-                        states
-                            .entry(c)
-                            .or_insert_with(|| (Vec::new(), Vec::new(), Vec::new()))
-                            .0
-                            .push(i);
-                    }
-                    TileMode::Present => {
-                        inferences.insert(Inference::new(c, i, InferenceKind::Present));
-                        states
-                            .entry(c)
-                            .or_insert_with(|| (Vec::new(), Vec::new(), Vec::new()))
-                            .1
-                            .push(i);
-                    }
-                    TileMode::Absent => {
-                        inferences.insert(Inference::new(c, i, InferenceKind::AbsentLocal));
-                        states
-                            .entry(c)
-                            .or_insert_with(|| (Vec::new(), Vec::new(), Vec::new()))
-                            .2
-                            .push(i);
-                    }
-                }
+/// How likely `word` is to be the answer, going by how common it is.  The scores are already
+/// logarithmic, so common words are favoured without swamping everything else.
+fn prior(word: &str) -> f64 {
+    wordle_dict::FREQUENCIES
+        .get(word)
+        .map_or(0.5, |&score| score as f64)
+}
+
+pub struct Worker {
+    /// The session from the last input, along with the word length and hard mode setting
+    /// its engine was built for.
+    session: Option<(usize, bool, Session<'static>)>,
+    link: AgentLink<Self>,
+}
+
+impl Worker {
+    /// Brings the session up to date with `boards`, only replaying the boards which changed
+    /// since the last input.
+    fn update_session(
+        &mut self,
+        len: usize,
+        boards: Vec<Vec<Inference>>,
+        hard_mode: bool,
+    ) -> Result<&Session<'static>, Contradiction> {
+        let stale = !matches!(self.session, Some((l, h, _)) if l == len && h == hard_mode);
+        if stale {
+            let mut engine = DynEngine::new(len, wordle_dict::words_of_len(len))
+                .hard_mode(hard_mode)
+                .prior(prior);
+            // Curated answers are only available for five letter words.
+            if len == 5 {
+                engine = engine.answers(wordle_dict::ANSWERS.iter().copied());
             }
+            self.session = Some((len, hard_mode, Session::new(engine)));
         }
+        let (_, _, session) = self.session.as_mut().unwrap();
 
-        // Board-level inferences
-        for (c, (correct, present, absent)) in states.into_iter() {
-            // We can put a lower (or exact) bound on the number of times `c` appears in the word.
-            let kind = if !correct.is_empty() || !present.is_empty() {
-                // We have correct or present tiles; this puts some restriction on valid words.
-                if !absent.is_empty() {
-                    // If we have absent tiles then we have an _exact_ bound on valid words.
-                    InferenceKind::Count(correct.len() + present.len())
-                } else {
-                    // If we don't have absent tiles then we only have a lower bound.
-                    InferenceKind::AtLeast(correct.len() + present.len())
-                }
-            } else {
-                InferenceKind::AbsentGlobal
-            };
-            inferences.insert(Inference::new(c, 0, kind));
-        }
-    }
+        let unchanged = boards
+            .iter()
+            .enumerate()
+            .take_while(|&(i, inferences)| session.turn(i) == Some(inferences.as_slice()))
+            .count();
 
-    inferences.into_iter().collect()
-}
+        session.truncate(unchanged);
+        for inferences in boards.into_iter().skip(unchanged) {
+            session.apply_inferences(inferences)?;
+        }
 
-pub struct Worker {
-    words: Vec<&'static str>,
-    link: AgentLink<Self>,
+        Ok(session)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct WorkerInput {
-    pub boards: Vec<Board>,
+    /// The length of the word being solved.
+    pub len: usize,
+    /// The inferences revealed by each board.
+    pub boards: Vec<Vec<Inference>>,
+    pub hard_mode: bool,
+    /// A word to explain the absence of, if any.
+    pub why_not: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct WorkerOutput {
+    /// The best guesses which could still be the answer.
     pub values: Vec<String>,
+    /// The best guesses from the whole dictionary, whether or not they could be the answer.
+    pub probes: Vec<String>,
+    /// Why the boards can't all be right, if they can't.
+    pub error: Option<String>,
+    /// The reasons the `why_not` word can't be the answer.
+    pub reasons: Vec<String>,
 }
 
-fn _index(c: char) -> usize {
-    ((c as u8) - (b'a')) as usize
+/// Takes the top 20 ranked words.
+fn top_words(results: Vec<(&'static str, f64)>) -> Vec<String> {
+    results
+        .into_iter()
+        .take(20)
+        .map(|(w, _)| String::from(w))
+        .collect()
 }
 
 impl Agent for Worker {
@@ -89,36 +93,51 @@ impl Agent for Worker {
     type Output = WorkerOutput;
 
     fn create(link: AgentLink<Self>) -> Self {
-        let mut words = Vec::new();
-
-        for word in wordle_dict::WORDS.iter() {
-            if word.len() == 5 {
-                words.push(*word);
-            }
+        Self {
+            link,
+            session: None,
         }
-
-        Self { link, words }
     }
 
     fn update(&mut self, _msg: Self::Message) {}
 
     fn handle_input(&mut self, msg: Self::Input, id: HandlerId) {
-        let engine = Engine::<5>::new(self.words.iter().copied());
-        let inferences = determine_inferences(&msg.boards);
-
-        let mut results = engine.evaluate(&inferences);
-        results.sort_by_key(|(w, overlap)| {
-            std::cmp::Reverse((overlap.total + (overlap.partial / 3), *w))
-        });
-
-        // take the top 20 results
-        let outputs = results
+        let session = match self.update_session(msg.len, msg.boards, msg.hard_mode) {
+            Ok(session) => session,
+            Err(contradiction) => {
+                let output = Self::Output {
+                    values: Vec::new(),
+                    probes: Vec::new(),
+                    error: Some(contradiction.to_string()),
+                    reasons: Vec::new(),
+                };
+                self.link.respond(id, output);
+                return;
+            }
+        };
+
+        let values = top_words(session.rank());
+        let probes = top_words(session.rank_probes());
+        let reasons = msg
+            .why_not
+            .map(|word| {
+                let inferences = session.knowledge().inferences();
+                session.engine().explain(&word, &inferences)
+            })
+            .unwrap_or_default()
             .into_iter()
-            .take(20)
-            .map(|(w, _)| String::from(w))
+            .map(|rejection| rejection.to_string())
             .collect();
 
-        self.link.respond(id, Self::Output { values: outputs });
+        self.link.respond(
+            id,
+            Self::Output {
+                values,
+                probes,
+                error: None,
+                reasons,
+            },
+        );
     }
 
     fn name_of_resource() -> &'static str {